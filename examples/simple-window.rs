@@ -1,5 +1,6 @@
 use rich_sdl2_rust::{
     color::Rgb,
+    event::Propagation,
     renderer::pen::Pen,
     ttf::{
         font::{pen, Font},
@@ -26,10 +27,12 @@ pub fn main() {
     let mut event = EventBox::new(&video);
     event.handle_window(Box::new(|e| {
         *message.borrow_mut() = format!("window event: {:?} {:?}", e.timestamp, e.details);
+        Propagation::Continue
     }));
     event.handle_quit(Box::new(|e| {
         eprintln!("quit event: {}", e.timestamp);
         exit.set(true);
+        Propagation::Continue
     }));
 
     while !exit.get() {
@@ -1,39 +1,238 @@
 //! SDL2 event system and handlers.
 //!
-//! Most event structures are defined in these sub modules, but [`crate::audio::event::AudioDeviceEvent`] is defined in the external module.
+//! Most event structures are defined in these sub modules, but [`crate::audio::event::AudioDeviceEvent`] and [`crate::clipboard::ClipboardUpdateEvent`] are defined in the external modules.
 
 use static_assertions::assert_not_impl_all;
 use std::marker::PhantomData;
 
 use self::{
-    app::QuitEvent,
+    app::{AppEvent, QuitEvent},
+    display::DisplayEvent,
     drop::DropEvent,
     game_controller::event::ControllerEvent,
     joystick::event::JoystickEvent,
-    keyboard::KeyboardEvent,
-    mouse::{MouseButtonEvent, MouseEvent, MouseMotionEvent, MouseWheelEvent},
+    keyboard::{KeyboardEvent, KeymapChangedEvent},
+    mouse::MouseEvent,
+    record::EventRecorder,
+    render::RenderEvent,
+    sensor::SensorEvent,
     text::{TextEditingEvent, TextInputEvent},
-    touch::gesture::GestureEvent,
+    touch::{gesture::GestureEvent, TouchFingerEvent},
+    user::{UserEvent, UserEventSender},
     window::WindowEvent,
 };
 
-use crate::{audio::event::AudioDeviceEvent, bind, EnumInt, Sdl, Video};
+use crate::{
+    audio::event::AudioDeviceEvent, bind, clipboard::ClipboardUpdateEvent, window::Window, EnumInt,
+    Sdl, Video,
+};
 
 pub mod app;
+pub mod display;
 pub mod drop;
+pub mod filter;
 pub mod game_controller;
+mod iter;
 pub mod joystick;
 pub mod keyboard;
 pub mod mouse;
+pub mod record;
+pub mod render;
 pub mod sensor;
 pub mod text;
 pub mod touch;
+pub mod user;
 pub mod window;
 
-/// An event handler to receive some event structure,
-pub type EventHandler<'video, T> = Box<dyn Fn(&T) + 'video>;
+pub use iter::*;
+
+/// An event decoded from the SDL2 event queue, holding one of the event structures.
+#[derive(Debug)]
+pub enum Event<'video> {
+    /// A [`QuitEvent`].
+    Quit(QuitEvent),
+    /// A [`WindowEvent`].
+    Window(WindowEvent),
+    /// A [`KeyboardEvent`].
+    Keyboard(KeyboardEvent),
+    /// A [`TextInputEvent`].
+    Input(TextInputEvent),
+    /// A [`TextEditingEvent`].
+    Editing(TextEditingEvent),
+    /// A [`MouseEvent`].
+    Mouse(MouseEvent),
+    /// A [`ControllerEvent`].
+    Controller(ControllerEvent<'video>),
+    /// A [`JoystickEvent`].
+    Joystick(JoystickEvent<'video>),
+    /// An [`AudioDeviceEvent`].
+    AudioDevice(AudioDeviceEvent),
+    /// A [`DropEvent`].
+    Drop(DropEvent),
+    /// A [`GestureEvent`].
+    Gesture(GestureEvent),
+    /// A [`SensorEvent`].
+    Sensor(SensorEvent),
+    /// A [`TouchFingerEvent`].
+    TouchFinger(TouchFingerEvent),
+    /// A [`KeymapChangedEvent`].
+    KeymapChanged(KeymapChangedEvent),
+    /// A [`ClipboardUpdateEvent`].
+    ClipboardUpdate(ClipboardUpdateEvent),
+    /// A [`RenderEvent`].
+    Render(RenderEvent),
+    /// A [`DisplayEvent`].
+    Display(DisplayEvent),
+    /// A [`UserEvent`].
+    User(UserEvent),
+    /// An [`AppEvent`].
+    App(AppEvent),
+}
+
+impl Event<'_> {
+    /// Returns the id of the window where the window, keyboard, mouse, text or drop event occurred, or `None` for the other events.
+    #[must_use]
+    pub fn window_id(&self) -> Option<u32> {
+        match self {
+            Event::Window(window) => Some(window.window_id),
+            Event::Keyboard(keyboard) => Some(keyboard.window_id),
+            Event::Input(input) => Some(input.window_id),
+            Event::Editing(editing) => Some(editing.window_id),
+            Event::Mouse(MouseEvent::Motion(motion)) => Some(motion.window_id),
+            Event::Mouse(MouseEvent::Button(button)) => Some(button.window_id),
+            Event::Mouse(MouseEvent::Wheel(wheel)) => Some(wheel.window_id),
+            Event::Drop(
+                DropEvent::File { window_id, .. }
+                | DropEvent::Text { window_id, .. }
+                | DropEvent::Begin { window_id, .. }
+                | DropEvent::Complete { window_id, .. },
+            ) => Some(*window_id),
+            _ => None,
+        }
+    }
+
+    /// Decodes the raw event without side effects, so nothing is moved out, freed or opened. [`UserEvent`] is not decoded and `None` is returned.
+    pub(crate) fn from_raw_ref(event: &bind::SDL_Event) -> Option<Self> {
+        let ty = unsafe { event.type_ };
+        if (bind::SDL_USEREVENT..bind::SDL_LASTEVENT).contains(&(ty as EnumInt)) {
+            return None;
+        }
+        if ty as EnumInt == bind::SDL_TEXTEDITING_EXT {
+            return Some(Event::Editing(TextEditingEvent::from_ext(unsafe {
+                &event.editExt
+            })));
+        }
+        Self::from_raw(*event)
+    }
+
+    /// Decodes the raw event, or returns `None` if the event is not supported by this wrapper.
+    ///
+    /// The raw event must have been removed from the event queue, because the value of [`UserEvent`] is moved out of it and the text of the extended [`TextEditingEvent`] is freed.
+    pub(crate) fn from_raw(event: bind::SDL_Event) -> Option<Self> {
+        let ty = unsafe { event.type_ };
+        let decoded = match ty as EnumInt {
+            bind::SDL_QUIT => Event::Quit(unsafe { event.quit }.into()),
+            bind::SDL_WINDOWEVENT => Event::Window(unsafe { event.window }.into()),
+            bind::SDL_KEYDOWN | bind::SDL_KEYUP => Event::Keyboard(unsafe { event.key }.into()),
+            bind::SDL_TEXTINPUT => Event::Input(unsafe { event.text }.into()),
+            bind::SDL_TEXTEDITING => Event::Editing(unsafe { event.edit }.into()),
+            bind::SDL_TEXTEDITING_EXT => {
+                let raw = unsafe { event.editExt };
+                let editing = TextEditingEvent::from_ext(&raw);
+                unsafe { bind::SDL_free(raw.text.cast()) };
+                Event::Editing(editing)
+            }
+            bind::SDL_MOUSEMOTION => {
+                Event::Mouse(MouseEvent::Motion(unsafe { event.motion }.into()))
+            }
+            bind::SDL_MOUSEBUTTONDOWN | bind::SDL_MOUSEBUTTONUP => {
+                Event::Mouse(MouseEvent::Button(unsafe { event.button }.into()))
+            }
+            bind::SDL_MOUSEWHEEL => Event::Mouse(MouseEvent::Wheel(unsafe { event.wheel }.into())),
+            bind::SDL_CONTROLLERAXISMOTION => Event::Controller(unsafe { event.caxis }.into()),
+            bind::SDL_CONTROLLERBUTTONDOWN | bind::SDL_CONTROLLERBUTTONUP => {
+                Event::Controller(unsafe { event.cbutton }.into())
+            }
+            bind::SDL_CONTROLLERDEVICEADDED
+            | bind::SDL_CONTROLLERDEVICEREMOVED
+            | bind::SDL_CONTROLLERDEVICEREMAPPED => {
+                Event::Controller(unsafe { event.cdevice }.into())
+            }
+            bind::SDL_CONTROLLERTOUCHPADDOWN
+            | bind::SDL_CONTROLLERTOUCHPADMOTION
+            | bind::SDL_CONTROLLERTOUCHPADUP => {
+                Event::Controller(unsafe { event.ctouchpad }.into())
+            }
+            bind::SDL_CONTROLLERSENSORUPDATE => Event::Controller(unsafe { event.csensor }.into()),
+            bind::SDL_JOYAXISMOTION => Event::Joystick(unsafe { event.jaxis }.into()),
+            bind::SDL_JOYBALLMOTION => Event::Joystick(unsafe { event.jball }.into()),
+            bind::SDL_JOYBUTTONDOWN | bind::SDL_JOYBUTTONUP => {
+                Event::Joystick(unsafe { event.jbutton }.into())
+            }
+            bind::SDL_JOYDEVICEADDED | bind::SDL_JOYDEVICEREMOVED => {
+                Event::Joystick(unsafe { event.jdevice }.into())
+            }
+            bind::SDL_JOYHATMOTION => Event::Joystick(unsafe { event.jhat }.into()),
+            bind::SDL_AUDIODEVICEADDED | bind::SDL_AUDIODEVICEREMOVED => {
+                Event::AudioDevice(unsafe { event.adevice }.into())
+            }
+            bind::SDL_DROPFILE
+            | bind::SDL_DROPTEXT
+            | bind::SDL_DROPBEGIN
+            | bind::SDL_DROPCOMPLETE => Event::Drop(unsafe { event.drop }.into()),
+            bind::SDL_MULTIGESTURE => Event::Gesture(unsafe { event.mgesture }.into()),
+            bind::SDL_DOLLARGESTURE | bind::SDL_DOLLARRECORD => {
+                Event::Gesture(unsafe { event.dgesture }.into())
+            }
+            bind::SDL_SENSORUPDATE => Event::Sensor(unsafe { event.sensor }.into()),
+            bind::SDL_FINGERDOWN | bind::SDL_FINGERMOTION | bind::SDL_FINGERUP => {
+                Event::TouchFinger(unsafe { event.tfinger }.into())
+            }
+            bind::SDL_KEYMAPCHANGED => Event::KeymapChanged(unsafe { event.common }.into()),
+            bind::SDL_CLIPBOARDUPDATE => Event::ClipboardUpdate(unsafe { event.common }.into()),
+            bind::SDL_RENDER_TARGETS_RESET | bind::SDL_RENDER_DEVICE_RESET => {
+                Event::Render(unsafe { event.common }.into())
+            }
+            bind::SDL_DISPLAYEVENT => Event::Display(unsafe { event.display }.into()),
+            bind::SDL_APP_TERMINATING
+            | bind::SDL_APP_LOWMEMORY
+            | bind::SDL_APP_WILLENTERBACKGROUND
+            | bind::SDL_APP_DIDENTERBACKGROUND
+            | bind::SDL_APP_WILLENTERFOREGROUND
+            | bind::SDL_APP_DIDENTERFOREGROUND
+            | bind::SDL_LOCALECHANGED => Event::App(unsafe { event.common }.into()),
+            ty if (bind::SDL_USEREVENT..bind::SDL_LASTEVENT).contains(&ty) => {
+                Event::User(unsafe { UserEvent::from_raw(event.user) }?)
+            }
+            _ => return None,
+        };
+        Some(decoded)
+    }
+}
+
+/// Whether the event is delivered to the handlers registered after the current handler.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Propagation {
+    /// The event is delivered to the later handlers too.
+    Continue,
+    /// The event is consumed, so the later handlers do not receive it.
+    Consume,
+}
 
-struct EventHandlers<'video, T>(Vec<EventHandler<'video, T>>);
+impl Default for Propagation {
+    fn default() -> Self {
+        Self::Continue
+    }
+}
+
+/// An event handler to receive some event structure. The handlers are called in the registered order, until a handler returns [`Propagation::Consume`].
+pub type EventHandler<'video, T> = Box<dyn FnMut(&T) -> Propagation + 'video>;
+
+/// An id of the event handler registered to [`EventBox`]. It can be used to remove the handler by [`EventBox::remove_handler`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HandlerId(u64);
+
+struct EventHandlers<'video, T>(Vec<(HandlerId, EventHandler<'video, T>)>);
 
 impl<T> Default for EventHandlers<'_, T> {
     fn default() -> Self {
@@ -42,12 +241,23 @@ impl<T> Default for EventHandlers<'_, T> {
 }
 
 impl<'video, T> EventHandlers<'video, T> {
-    fn push(&mut self, handler: EventHandler<'video, T>) {
-        self.0.push(handler);
+    fn push(&mut self, id: HandlerId, handler: EventHandler<'video, T>) {
+        self.0.push((id, handler));
+    }
+
+    fn remove(&mut self, id: HandlerId) -> bool {
+        let prev_len = self.0.len();
+        self.0.retain(|(registered, _)| *registered != id);
+        self.0.len() != prev_len
     }
 
-    fn call_handlers(&self, event: &T) {
-        self.0.iter().for_each(|handler| handler(event));
+    fn call_handlers(&mut self, event: &T) -> Propagation {
+        for (_, handler) in &mut self.0 {
+            if handler(event) == Propagation::Consume {
+                return Propagation::Consume;
+            }
+        }
+        Propagation::Continue
     }
 }
 
@@ -64,6 +274,17 @@ pub struct EventBox<'video> {
     audio_device_event_handlers: EventHandlers<'video, AudioDeviceEvent>,
     drop_event_handlers: EventHandlers<'video, DropEvent>,
     gesture_event_handlers: EventHandlers<'video, GestureEvent>,
+    sensor_event_handlers: EventHandlers<'video, SensorEvent>,
+    touch_finger_event_handlers: EventHandlers<'video, TouchFingerEvent>,
+    keymap_changed_event_handlers: EventHandlers<'video, KeymapChangedEvent>,
+    clipboard_update_event_handlers: EventHandlers<'video, ClipboardUpdateEvent>,
+    render_event_handlers: EventHandlers<'video, RenderEvent>,
+    display_event_handlers: EventHandlers<'video, DisplayEvent>,
+    user_event_handlers: EventHandlers<'video, UserEvent>,
+    app_event_handlers: EventHandlers<'video, AppEvent>,
+    window_of_event_handlers: EventHandlers<'video, Event<'video>>,
+    next_handler_id: u64,
+    recorder: Option<EventRecorder>,
     _phantom: PhantomData<&'video ()>,
 }
 
@@ -89,182 +310,342 @@ impl<'video> EventBox<'video> {
             audio_device_event_handlers: EventHandlers::default(),
             drop_event_handlers: EventHandlers::default(),
             gesture_event_handlers: EventHandlers::default(),
+            sensor_event_handlers: EventHandlers::default(),
+            touch_finger_event_handlers: EventHandlers::default(),
+            keymap_changed_event_handlers: EventHandlers::default(),
+            clipboard_update_event_handlers: EventHandlers::default(),
+            render_event_handlers: EventHandlers::default(),
+            display_event_handlers: EventHandlers::default(),
+            user_event_handlers: EventHandlers::default(),
+            app_event_handlers: EventHandlers::default(),
+            window_of_event_handlers: EventHandlers::default(),
+            next_handler_id: 0,
+            recorder: None,
             _phantom: PhantomData,
         }
     }
 
-    /// Registers the handler to handle [`QuitEvent`].
-    pub fn handle_quit(&mut self, handler: EventHandler<'video, QuitEvent>) {
-        self.quit_event_handlers.push(handler);
+    fn issue_handler_id(&mut self) -> HandlerId {
+        let id = HandlerId(self.next_handler_id);
+        self.next_handler_id += 1;
+        id
     }
 
-    /// Registers the handler to handle [`WindowEvent`].
-    pub fn handle_window(&mut self, handler: EventHandler<'video, WindowEvent>) {
-        self.window_event_handlers.push(handler);
+    /// Removes the handler registered with `id`, and returns whether it was registered.
+    pub fn remove_handler(&mut self, id: HandlerId) -> bool {
+        self.quit_event_handlers.remove(id)
+            || self.window_event_handlers.remove(id)
+            || self.keyboard_event_handlers.remove(id)
+            || self.input_event_handlers.remove(id)
+            || self.editing_event_handlers.remove(id)
+            || self.mouse_event_handlers.remove(id)
+            || self.controller_event_handlers.remove(id)
+            || self.joystick_event_handlers.remove(id)
+            || self.audio_device_event_handlers.remove(id)
+            || self.drop_event_handlers.remove(id)
+            || self.gesture_event_handlers.remove(id)
+            || self.sensor_event_handlers.remove(id)
+            || self.touch_finger_event_handlers.remove(id)
+            || self.keymap_changed_event_handlers.remove(id)
+            || self.clipboard_update_event_handlers.remove(id)
+            || self.render_event_handlers.remove(id)
+            || self.display_event_handlers.remove(id)
+            || self.user_event_handlers.remove(id)
+            || self.app_event_handlers.remove(id)
+            || self.window_of_event_handlers.remove(id)
     }
 
-    /// Registers the handler to handle [`KeyboardEvent`].
-    pub fn handle_keyboard(&mut self, handler: EventHandler<'video, KeyboardEvent>) {
-        self.keyboard_event_handlers.push(handler);
+    /// Registers the handler to handle [`QuitEvent`], and returns the id to remove it later.
+    pub fn handle_quit(&mut self, handler: EventHandler<'video, QuitEvent>) -> HandlerId {
+        let id = self.issue_handler_id();
+        self.quit_event_handlers.push(id, handler);
+        id
     }
 
-    /// Registers the handler to handle [`TextInputEvent`].
-    pub fn handle_input(&mut self, handler: EventHandler<'video, TextInputEvent>) {
-        self.input_event_handlers.push(handler);
+    /// Registers the handler to handle [`WindowEvent`], and returns the id to remove it later.
+    pub fn handle_window(&mut self, handler: EventHandler<'video, WindowEvent>) -> HandlerId {
+        let id = self.issue_handler_id();
+        self.window_event_handlers.push(id, handler);
+        id
     }
 
-    /// Registers the handler to handle [`TextEditingEvent`].
-    pub fn handle_editing(&mut self, handler: EventHandler<'video, TextEditingEvent>) {
-        self.editing_event_handlers.push(handler);
+    /// Registers the handler to handle [`KeyboardEvent`], and returns the id to remove it later.
+    pub fn handle_keyboard(&mut self, handler: EventHandler<'video, KeyboardEvent>) -> HandlerId {
+        let id = self.issue_handler_id();
+        self.keyboard_event_handlers.push(id, handler);
+        id
     }
 
-    /// Registers the handler to handle [`MouseEvent`].
-    pub fn handle_mouse(&mut self, handler: EventHandler<'video, MouseEvent>) {
-        self.mouse_event_handlers.push(handler);
+    /// Registers the handler to handle [`TextInputEvent`], and returns the id to remove it later.
+    pub fn handle_input(&mut self, handler: EventHandler<'video, TextInputEvent>) -> HandlerId {
+        let id = self.issue_handler_id();
+        self.input_event_handlers.push(id, handler);
+        id
     }
 
-    /// Registers the handler to handle [`ControllerEvent`].
-    pub fn handle_controller(&mut self, handler: EventHandler<'video, ControllerEvent<'video>>) {
-        self.controller_event_handlers.push(handler);
+    /// Registers the handler to handle [`TextEditingEvent`], and returns the id to remove it later.
+    pub fn handle_editing(&mut self, handler: EventHandler<'video, TextEditingEvent>) -> HandlerId {
+        let id = self.issue_handler_id();
+        self.editing_event_handlers.push(id, handler);
+        id
     }
 
-    /// Registers the handler to handle [`JoystickEvent`].
-    pub fn handle_joystick(&mut self, handler: EventHandler<'video, JoystickEvent<'video>>) {
-        self.joystick_event_handlers.push(handler);
+    /// Registers the handler to handle [`MouseEvent`], and returns the id to remove it later.
+    pub fn handle_mouse(&mut self, handler: EventHandler<'video, MouseEvent>) -> HandlerId {
+        let id = self.issue_handler_id();
+        self.mouse_event_handlers.push(id, handler);
+        id
     }
 
-    /// Registers the handler to handle [`AudioDeviceEvent`].
-    pub fn handle_audio_device(&mut self, handler: EventHandler<'video, AudioDeviceEvent>) {
-        self.audio_device_event_handlers.push(handler);
+    /// Registers the handler to handle [`ControllerEvent`], and returns the id to remove it later.
+    pub fn handle_controller(
+        &mut self,
+        handler: EventHandler<'video, ControllerEvent<'video>>,
+    ) -> HandlerId {
+        let id = self.issue_handler_id();
+        self.controller_event_handlers.push(id, handler);
+        id
     }
 
-    /// Registers the handler to handle [`DropEvent`].
-    pub fn handle_drop(&mut self, handler: EventHandler<'video, DropEvent>) {
-        self.drop_event_handlers.push(handler);
+    /// Registers the handler to handle [`JoystickEvent`], and returns the id to remove it later.
+    pub fn handle_joystick(
+        &mut self,
+        handler: EventHandler<'video, JoystickEvent<'video>>,
+    ) -> HandlerId {
+        let id = self.issue_handler_id();
+        self.joystick_event_handlers.push(id, handler);
+        id
     }
 
-    /// Registers the handler to handle [`GestureEvent`].
-    pub fn handle_gesture(&mut self, handler: EventHandler<'video, GestureEvent>) {
-        self.gesture_event_handlers.push(handler);
+    /// Registers the handler to handle [`AudioDeviceEvent`], and returns the id to remove it later.
+    pub fn handle_audio_device(
+        &mut self,
+        handler: EventHandler<'video, AudioDeviceEvent>,
+    ) -> HandlerId {
+        let id = self.issue_handler_id();
+        self.audio_device_event_handlers.push(id, handler);
+        id
     }
 
-    fn handle_event(&self, event: bind::SDL_Event) {
-        let ty = unsafe { event.type_ };
-        match ty as EnumInt {
-            bind::SDL_QUIT => {
-                let quit: QuitEvent = unsafe { event.quit }.into();
-                self.quit_event_handlers.call_handlers(&quit);
-            }
-            bind::SDL_WINDOWEVENT => {
-                let window: WindowEvent = unsafe { event.window }.into();
-                self.window_event_handlers.call_handlers(&window);
-            }
-            bind::SDL_KEYDOWN | bind::SDL_KEYUP => {
-                let keyboard: KeyboardEvent = unsafe { event.key }.into();
-                self.keyboard_event_handlers.call_handlers(&keyboard);
-            }
-            bind::SDL_TEXTINPUT => {
-                let input: TextInputEvent = unsafe { event.text }.into();
-                self.input_event_handlers.call_handlers(&input);
-            }
-            bind::SDL_TEXTEDITING => {
-                let editing: TextEditingEvent = unsafe { event.edit }.into();
-                self.editing_event_handlers.call_handlers(&editing);
-            }
-            bind::SDL_MOUSEMOTION => {
-                let motion: MouseMotionEvent = unsafe { event.motion }.into();
-                let mouse = MouseEvent::Motion(motion);
-                self.mouse_event_handlers.call_handlers(&mouse);
-            }
-            bind::SDL_MOUSEBUTTONDOWN | bind::SDL_MOUSEBUTTONUP => {
-                let button: MouseButtonEvent = unsafe { event.button }.into();
-                let mouse = MouseEvent::Button(button);
-                self.mouse_event_handlers.call_handlers(&mouse);
-            }
-            bind::SDL_MOUSEWHEEL => {
-                let wheel: MouseWheelEvent = unsafe { event.wheel }.into();
-                let mouse = MouseEvent::Wheel(wheel);
-                self.mouse_event_handlers.call_handlers(&mouse);
-            }
-            bind::SDL_CONTROLLERAXISMOTION => {
-                let con: ControllerEvent = unsafe { event.caxis }.into();
-                self.controller_event_handlers.call_handlers(&con);
-            }
-            bind::SDL_CONTROLLERBUTTONDOWN | bind::SDL_CONTROLLERBUTTONUP => {
-                let con: ControllerEvent = unsafe { event.cbutton }.into();
-                self.controller_event_handlers.call_handlers(&con);
-            }
-            bind::SDL_CONTROLLERDEVICEADDED
-            | bind::SDL_CONTROLLERDEVICEREMOVED
-            | bind::SDL_CONTROLLERDEVICEREMAPPED => {
-                let con: ControllerEvent = unsafe { event.cdevice }.into();
-                self.controller_event_handlers.call_handlers(&con);
-            }
-            bind::SDL_JOYAXISMOTION => {
-                let joy: JoystickEvent = unsafe { event.jaxis }.into();
-                self.joystick_event_handlers.call_handlers(&joy);
-            }
-            bind::SDL_JOYBALLMOTION => {
-                let joy: JoystickEvent = unsafe { event.jball }.into();
-                self.joystick_event_handlers.call_handlers(&joy);
-            }
-            bind::SDL_JOYBUTTONDOWN | bind::SDL_JOYBUTTONUP => {
-                let joy: JoystickEvent = unsafe { event.jbutton }.into();
-                self.joystick_event_handlers.call_handlers(&joy);
-            }
-            bind::SDL_JOYDEVICEADDED | bind::SDL_JOYDEVICEREMOVED => {
-                let joy: JoystickEvent = unsafe { event.jdevice }.into();
-                self.joystick_event_handlers.call_handlers(&joy);
-            }
-            bind::SDL_JOYHATMOTION => {
-                let joy: JoystickEvent = unsafe { event.jhat }.into();
-                self.joystick_event_handlers.call_handlers(&joy);
-            }
-            bind::SDL_AUDIODEVICEADDED | bind::SDL_AUDIODEVICEREMOVED => {
-                let audio = unsafe { event.adevice }.into();
-                self.audio_device_event_handlers.call_handlers(&audio);
-            }
-            bind::SDL_DROPFILE
-            | bind::SDL_DROPTEXT
-            | bind::SDL_DROPBEGIN
-            | bind::SDL_DROPCOMPLETE => {
-                let drop = unsafe { event.drop }.into();
-                self.drop_event_handlers.call_handlers(&drop);
-            }
-            bind::SDL_MULTIGESTURE => {
-                let gesture = unsafe { event.mgesture }.into();
-                self.gesture_event_handlers.call_handlers(&gesture);
-            }
-            bind::SDL_DOLLARGESTURE => {
-                let gesture = unsafe { event.dgesture }.into();
-                self.gesture_event_handlers.call_handlers(&gesture);
+    /// Registers the handler to handle [`DropEvent`], and returns the id to remove it later.
+    pub fn handle_drop(&mut self, handler: EventHandler<'video, DropEvent>) -> HandlerId {
+        let id = self.issue_handler_id();
+        self.drop_event_handlers.push(id, handler);
+        id
+    }
+
+    /// Registers the handler to handle [`GestureEvent`], and returns the id to remove it later.
+    pub fn handle_gesture(&mut self, handler: EventHandler<'video, GestureEvent>) -> HandlerId {
+        let id = self.issue_handler_id();
+        self.gesture_event_handlers.push(id, handler);
+        id
+    }
+
+    /// Registers the handler to handle [`SensorEvent`], and returns the id to remove it later.
+    pub fn handle_sensor(&mut self, handler: EventHandler<'video, SensorEvent>) -> HandlerId {
+        let id = self.issue_handler_id();
+        self.sensor_event_handlers.push(id, handler);
+        id
+    }
+
+    /// Registers the handler to handle [`TouchFingerEvent`], and returns the id to remove it later.
+    pub fn handle_touch_finger(
+        &mut self,
+        handler: EventHandler<'video, TouchFingerEvent>,
+    ) -> HandlerId {
+        let id = self.issue_handler_id();
+        self.touch_finger_event_handlers.push(id, handler);
+        id
+    }
+
+    /// Registers the handler to handle [`KeymapChangedEvent`], and returns the id to remove it later.
+    pub fn handle_keymap_changed(
+        &mut self,
+        handler: EventHandler<'video, KeymapChangedEvent>,
+    ) -> HandlerId {
+        let id = self.issue_handler_id();
+        self.keymap_changed_event_handlers.push(id, handler);
+        id
+    }
+
+    /// Registers the handler to handle [`ClipboardUpdateEvent`], and returns the id to remove it later.
+    pub fn handle_clipboard_update(
+        &mut self,
+        handler: EventHandler<'video, ClipboardUpdateEvent>,
+    ) -> HandlerId {
+        let id = self.issue_handler_id();
+        self.clipboard_update_event_handlers.push(id, handler);
+        id
+    }
+
+    /// Registers the handler to handle [`RenderEvent`], and returns the id to remove it later.
+    pub fn handle_render(&mut self, handler: EventHandler<'video, RenderEvent>) -> HandlerId {
+        let id = self.issue_handler_id();
+        self.render_event_handlers.push(id, handler);
+        id
+    }
+
+    /// Registers the handler to handle [`DisplayEvent`], and returns the id to remove it later.
+    pub fn handle_display(&mut self, handler: EventHandler<'video, DisplayEvent>) -> HandlerId {
+        let id = self.issue_handler_id();
+        self.display_event_handlers.push(id, handler);
+        id
+    }
+
+    /// Registers the handler to handle the values sent by `sender`, and returns the id to remove it later. The value is dropped after all the handlers are called.
+    pub fn handle_user<T: Send + 'static>(
+        &mut self,
+        sender: &UserEventSender<T>,
+        mut handler: EventHandler<'video, T>,
+    ) -> HandlerId {
+        let sender = *sender;
+        let id = self.issue_handler_id();
+        self.user_event_handlers.push(
+            id,
+            Box::new(move |event: &UserEvent| {
+                if !event.is_sent_by(&sender) {
+                    return Propagation::Continue;
+                }
+                event
+                    .downcast_ref::<T>()
+                    .map_or(Propagation::Continue, |value| handler(value))
+            }),
+        );
+        id
+    }
+
+    /// Registers the handler to handle [`AppEvent`], and returns the id to remove it later.
+    ///
+    /// Note that the handlers are called on polling, so use [`filter::EventWatch`] instead to handle them synchronously.
+    pub fn handle_app(&mut self, handler: EventHandler<'video, AppEvent>) -> HandlerId {
+        let id = self.issue_handler_id();
+        self.app_event_handlers.push(id, handler);
+        id
+    }
+
+    /// Registers the handler to handle the window, keyboard, mouse, text and drop events occurred on the window, and returns the id to remove it later. Register this for each window to route the events to several windows.
+    ///
+    /// These handlers are called before the handlers for each event type, so consuming the event here stops delivering it to them.
+    pub fn handle_window_of(
+        &mut self,
+        window: &Window,
+        mut handler: EventHandler<'video, Event<'video>>,
+    ) -> HandlerId {
+        let window_id = window.id();
+        let id = self.issue_handler_id();
+        self.window_of_event_handlers.push(
+            id,
+            Box::new(move |event| {
+                if event.window_id() == Some(window_id) {
+                    handler(event)
+                } else {
+                    Propagation::Continue
+                }
+            }),
+        );
+        id
+    }
+
+    fn handle_event(&mut self, event: &Event<'video>) {
+        if self.window_of_event_handlers.call_handlers(event) == Propagation::Consume {
+            return;
+        }
+        match event {
+            Event::Quit(quit) => self.quit_event_handlers.call_handlers(quit),
+            Event::Window(window) => self.window_event_handlers.call_handlers(window),
+            Event::Keyboard(keyboard) => self.keyboard_event_handlers.call_handlers(keyboard),
+            Event::Input(input) => self.input_event_handlers.call_handlers(input),
+            Event::Editing(editing) => self.editing_event_handlers.call_handlers(editing),
+            Event::Mouse(mouse) => self.mouse_event_handlers.call_handlers(mouse),
+            Event::Controller(con) => self.controller_event_handlers.call_handlers(con),
+            Event::Joystick(joy) => self.joystick_event_handlers.call_handlers(joy),
+            Event::AudioDevice(audio) => self.audio_device_event_handlers.call_handlers(audio),
+            Event::Drop(drop) => self.drop_event_handlers.call_handlers(drop),
+            Event::Gesture(gesture) => self.gesture_event_handlers.call_handlers(gesture),
+            Event::Sensor(sensor) => self.sensor_event_handlers.call_handlers(sensor),
+            Event::TouchFinger(finger) => self.touch_finger_event_handlers.call_handlers(finger),
+            Event::KeymapChanged(keymap) => {
+                self.keymap_changed_event_handlers.call_handlers(keymap)
             }
-            _ => {}
+            Event::ClipboardUpdate(clipboard) => self
+                .clipboard_update_event_handlers
+                .call_handlers(clipboard),
+            Event::Render(render) => self.render_event_handlers.call_handlers(render),
+            Event::Display(display) => self.display_event_handlers.call_handlers(display),
+            Event::User(user) => self.user_event_handlers.call_handlers(user),
+            Event::App(app) => self.app_event_handlers.call_handlers(app),
+        };
+    }
+
+    /// Attaches the recorder to record the events decoded by this, and returns the previously attached one.
+    pub fn attach_recorder(&mut self, recorder: EventRecorder) -> Option<EventRecorder> {
+        self.recorder.replace(recorder)
+    }
+
+    /// Returns the attached recorder if exists.
+    pub fn recorder_mut(&mut self) -> Option<&mut EventRecorder> {
+        self.recorder.as_mut()
+    }
+
+    /// Detaches the attached recorder if exists.
+    pub fn detach_recorder(&mut self) -> Option<EventRecorder> {
+        self.recorder.take()
+    }
+
+    fn decode(&mut self, raw: bind::SDL_Event) -> Option<Event<'video>> {
+        let event = Event::from_raw(raw)?;
+        if let Some(recorder) = &mut self.recorder {
+            recorder.record(&raw);
         }
+        Some(event)
     }
 
-    /// Polling the events and triggers the event handlers.
-    pub fn poll(&self) {
+    fn poll_raw(&self) -> Option<bind::SDL_Event> {
         use std::mem::MaybeUninit;
         let mut event = MaybeUninit::uninit();
         let remaining_events = unsafe { bind::SDL_PollEvent(event.as_mut_ptr()) };
-        let event = unsafe { event.assume_init() };
-        if remaining_events == 0 {
-            return;
-        }
-        self.handle_event(event);
+        (remaining_events != 0).then(|| unsafe { event.assume_init() })
     }
 
-    /// Waits until the next event occurs, but unlock with timeout seconds.
-    pub fn wait_next_event_with(&self, timeout_ms: u32) {
+    fn wait_raw(&self, timeout_ms: Option<u32>) -> Option<bind::SDL_Event> {
         use std::mem::MaybeUninit;
         let mut event = MaybeUninit::uninit();
-        let ret = unsafe { bind::SDL_WaitEventTimeout(event.as_mut_ptr(), timeout_ms as i32) };
-        if ret == 0 {
-            return;
+        let ret = match timeout_ms {
+            Some(timeout_ms) => unsafe {
+                bind::SDL_WaitEventTimeout(event.as_mut_ptr(), timeout_ms as i32)
+            },
+            None => unsafe { bind::SDL_WaitEvent(event.as_mut_ptr()) },
+        };
+        (ret != 0).then(|| unsafe { event.assume_init() })
+    }
+
+    /// Polling the events and triggers the event handlers.
+    pub fn poll(&mut self) {
+        if let Some(event) = self.poll_raw().and_then(|raw| self.decode(raw)) {
+            self.handle_event(&event);
+        }
+    }
+
+    /// Waits until the next event occurs, but unlock with timeout seconds.
+    pub fn wait_next_event_with(&mut self, timeout_ms: u32) {
+        if let Some(event) = self
+            .wait_raw(Some(timeout_ms))
+            .and_then(|raw| self.decode(raw))
+        {
+            self.handle_event(&event);
         }
-        let event = unsafe { event.assume_init() };
-        self.handle_event(event);
+    }
+
+    /// Returns an iterator that drains all the events in the queue, and ends when the queue becomes empty. Each event is also delivered to the registered handlers before yielded.
+    #[must_use]
+    pub fn poll_iter(&mut self) -> PollIter<'_, 'video> {
+        PollIter::new(self)
+    }
+
+    /// Returns an iterator that waits for the next event on every iteration, so it never ends unless an error occurs. Each event is also delivered to the registered handlers before yielded.
+    #[must_use]
+    pub fn wait_iter(&mut self) -> WaitIter<'_, 'video> {
+        WaitIter::new(self)
     }
 }
 
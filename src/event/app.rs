@@ -1,6 +1,6 @@
 //! Events occurring on the application.
 
-use crate::bind;
+use crate::{bind, EnumInt};
 
 /// An event on quitting the application.
 #[derive(Debug, Clone)]
@@ -14,3 +14,61 @@ impl From<bind::SDL_QuitEvent> for QuitEvent {
         Self { timestamp }
     }
 }
+
+/// An event on the application lifecycle, mainly occurs on mobile platforms.
+///
+/// Some of them must be handled synchronously by [`super::filter::EventWatch`], because the application may be suspended before polling.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppEvent {
+    /// The application is being terminated by the OS.
+    Terminating {
+        /// When this event occurred.
+        timestamp: u32,
+    },
+    /// The application is low on memory, so you should free memory as possible.
+    LowMemory {
+        /// When this event occurred.
+        timestamp: u32,
+    },
+    /// The application is about to enter the background.
+    WillEnterBackground {
+        /// When this event occurred.
+        timestamp: u32,
+    },
+    /// The application entered the background and may not get CPU for some time.
+    DidEnterBackground {
+        /// When this event occurred.
+        timestamp: u32,
+    },
+    /// The application is about to enter the foreground.
+    WillEnterForeground {
+        /// When this event occurred.
+        timestamp: u32,
+    },
+    /// The application entered the foreground and is now interactive.
+    DidEnterForeground {
+        /// When this event occurred.
+        timestamp: u32,
+    },
+    /// The preferred locales of the user were changed. See also [`crate::locale::preferred_locales`].
+    LocaleChanged {
+        /// When this event occurred.
+        timestamp: u32,
+    },
+}
+
+impl From<bind::SDL_CommonEvent> for AppEvent {
+    fn from(raw: bind::SDL_CommonEvent) -> Self {
+        let timestamp = raw.timestamp;
+        match raw.type_ as EnumInt {
+            bind::SDL_APP_TERMINATING => Self::Terminating { timestamp },
+            bind::SDL_APP_LOWMEMORY => Self::LowMemory { timestamp },
+            bind::SDL_APP_WILLENTERBACKGROUND => Self::WillEnterBackground { timestamp },
+            bind::SDL_APP_DIDENTERBACKGROUND => Self::DidEnterBackground { timestamp },
+            bind::SDL_APP_WILLENTERFOREGROUND => Self::WillEnterForeground { timestamp },
+            bind::SDL_APP_DIDENTERFOREGROUND => Self::DidEnterForeground { timestamp },
+            bind::SDL_LOCALECHANGED => Self::LocaleChanged { timestamp },
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Events for the display.
+
+use crate::{bind, EnumInt};
+
+/// An orientation of the display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisplayOrientation {
+    /// The orientation could not be determined.
+    Unknown,
+    /// The display is in landscape mode, with the right side up.
+    Landscape,
+    /// The display is in landscape mode, with the left side up.
+    LandscapeFlipped,
+    /// The display is in portrait mode.
+    Portrait,
+    /// The display is in portrait mode, upside down.
+    PortraitFlipped,
+}
+
+impl DisplayOrientation {
+    fn from_raw(raw: bind::SDL_DisplayOrientation) -> Self {
+        match raw {
+            bind::SDL_ORIENTATION_LANDSCAPE => DisplayOrientation::Landscape,
+            bind::SDL_ORIENTATION_LANDSCAPE_FLIPPED => DisplayOrientation::LandscapeFlipped,
+            bind::SDL_ORIENTATION_PORTRAIT => DisplayOrientation::Portrait,
+            bind::SDL_ORIENTATION_PORTRAIT_FLIPPED => DisplayOrientation::PortraitFlipped,
+            _ => DisplayOrientation::Unknown,
+        }
+    }
+}
+
+/// The details what occurred in [`DisplayEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisplayEventDetails {
+    /// The orientation of the display was changed.
+    Orientation(DisplayOrientation),
+    /// The display was connected.
+    Connected,
+    /// The display was disconnected.
+    Disconnected,
+    /// The kind of the event is unknown, sent by the newer SDL runtime.
+    Unknown(u8),
+}
+
+/// An event on the display was changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisplayEvent {
+    /// When this event occurred.
+    pub timestamp: u32,
+    /// The index of the display.
+    pub display_index: u32,
+    /// The kind of the event.
+    pub details: DisplayEventDetails,
+}
+
+impl From<bind::SDL_DisplayEvent> for DisplayEvent {
+    fn from(raw: bind::SDL_DisplayEvent) -> Self {
+        Self {
+            timestamp: raw.timestamp,
+            display_index: raw.display,
+            details: match raw.event as EnumInt {
+                bind::SDL_DISPLAYEVENT_ORIENTATION => DisplayEventDetails::Orientation(
+                    DisplayOrientation::from_raw(raw.data1 as bind::SDL_DisplayOrientation),
+                ),
+                bind::SDL_DISPLAYEVENT_CONNECTED => DisplayEventDetails::Connected,
+                bind::SDL_DISPLAYEVENT_DISCONNECTED => DisplayEventDetails::Disconnected,
+                _ => DisplayEventDetails::Unknown(raw.event),
+            },
+        }
+    }
+}
@@ -0,0 +1,185 @@
+//! Filtering and watching events before they are queued.
+//!
+//! The callbacks may be invoked from the thread which pushed the event, so they must be [`Send`]. [`super::user::UserEvent`]s are always passed through without invoking the callbacks.
+//! A panic in [`EventFilter`] or [`EventWatch`] callback is caught and the event is passed through, because it must not unwind into SDL2.
+//!
+//! Setting or removing [`EventFilter`] discards all the pending events in the queue as SDL2 does, and the values they own such as the payloads of [`super::user::UserEvent`]s are dropped.
+
+use std::{
+    any::Any,
+    ffi::c_void,
+    os::raw::c_int,
+    panic::{catch_unwind, resume_unwind, AssertUnwindSafe},
+    ptr::null_mut,
+};
+
+use crate::{bind, EnumInt};
+
+use super::{user::UserEvent, Event};
+
+/// A callback for [`EventFilter`] and [`EventWatch`]. Returning `false` from the filter drops the event, but the return value from the watch is ignored.
+pub type EventFilterCallback<'callback> = Box<dyn FnMut(&Event) -> bool + Send + 'callback>;
+
+/// An event filter that decides whether an event is added to the queue. Only one filter can be active at a time, so constructing a new filter replaces the old one.
+/// Dropping this will remove the filter. Both of them discard the pending events in the queue.
+pub struct EventFilter<'callback> {
+    raw: *mut EventFilterCallback<'callback>,
+}
+
+impl std::fmt::Debug for EventFilter<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventFilter").finish_non_exhaustive()
+    }
+}
+
+impl<'callback> EventFilter<'callback> {
+    /// Constructs and sets the event filter with the callback. The pending events in the queue are discarded.
+    #[must_use]
+    pub fn new(callback: EventFilterCallback<'callback>) -> Self {
+        let raw = Box::into_raw(Box::new(callback));
+        discard_queued_events();
+        unsafe { bind::SDL_SetEventFilter(Some(event_filter_wrap_handler), raw.cast()) }
+        Self { raw }
+    }
+}
+
+impl Drop for EventFilter<'_> {
+    fn drop(&mut self) {
+        let mut current = None;
+        let mut userdata = null_mut();
+        let is_set = unsafe { bind::SDL_GetEventFilter(&mut current, &mut userdata) };
+        if is_set == bind::SDL_TRUE && userdata == self.raw.cast() {
+            discard_queued_events();
+            unsafe { bind::SDL_SetEventFilter(None, null_mut()) }
+        }
+        let _ = unsafe { Box::from_raw(self.raw) };
+    }
+}
+
+/// An event watch that observes events synchronously when they are added to the queue, before polling them.
+/// Dropping this will remove the watch.
+pub struct EventWatch<'callback> {
+    raw: *mut EventFilterCallback<'callback>,
+}
+
+impl std::fmt::Debug for EventWatch<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventWatch").finish_non_exhaustive()
+    }
+}
+
+impl<'callback> EventWatch<'callback> {
+    /// Constructs and adds the event watch with the callback.
+    #[must_use]
+    pub fn new(callback: EventFilterCallback<'callback>) -> Self {
+        let raw = Box::into_raw(Box::new(callback));
+        unsafe { bind::SDL_AddEventWatch(Some(event_watch_wrap_handler), raw.cast()) }
+        Self { raw }
+    }
+}
+
+impl Drop for EventWatch<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            bind::SDL_DelEventWatch(Some(event_watch_wrap_handler), self.raw.cast());
+            let _ = Box::from_raw(self.raw);
+        }
+    }
+}
+
+unsafe extern "C" fn event_filter_wrap_handler(
+    userdata: *mut c_void,
+    event: *mut bind::SDL_Event,
+) -> c_int {
+    let callback = &mut *userdata.cast::<EventFilterCallback>();
+    let passes = catch_unwind(AssertUnwindSafe(|| call_filter(callback, &*event))).unwrap_or(true);
+    if !passes {
+        free_discarded_event(&*event);
+    }
+    passes as c_int
+}
+
+unsafe extern "C" fn event_watch_wrap_handler(
+    userdata: *mut c_void,
+    event: *mut bind::SDL_Event,
+) -> c_int {
+    let callback = &mut *userdata.cast::<EventFilterCallback>();
+    let _ = catch_unwind(AssertUnwindSafe(|| call_filter(callback, &*event)));
+    1
+}
+
+fn call_filter<F: FnMut(&Event) -> bool + ?Sized>(
+    callback: &mut F,
+    event: &bind::SDL_Event,
+) -> bool {
+    Event::from_raw_ref(event).map_or(true, |event| callback(&event))
+}
+
+/// Frees the text or value owned by the event, which SDL2 does not free when the event was rejected by the filter or discarded from the queue.
+unsafe fn free_discarded_event(event: &bind::SDL_Event) {
+    match event.type_ as EnumInt {
+        bind::SDL_TEXTEDITING_EXT => bind::SDL_free(event.editExt.text.cast()),
+        bind::SDL_DROPFILE | bind::SDL_DROPTEXT => bind::SDL_free(event.drop.file.cast()),
+        ty if (bind::SDL_USEREVENT..bind::SDL_LASTEVENT).contains(&ty) => {
+            UserEvent::free_raw(&event.user);
+        }
+        _ => {}
+    }
+}
+
+/// Removes all the events from the queue with freeing them, because `SDL_SetEventFilter` flushes the queue without freeing.
+fn discard_queued_events() {
+    unsafe { bind::SDL_FilterEvents(Some(discard_queued_event_wrap_handler), null_mut()) }
+}
+
+unsafe extern "C" fn discard_queued_event_wrap_handler(
+    _: *mut c_void,
+    event: *mut bind::SDL_Event,
+) -> c_int {
+    free_discarded_event(&*event);
+    0
+}
+
+struct QueuedFilter<F> {
+    callback: F,
+    panic: Option<Box<dyn Any + Send>>,
+}
+
+/// Removes the events that `callback` returned `false` from the current event queue.
+///
+/// # Panics
+///
+/// Panics if `callback` panicked, after the remaining events are kept in the queue.
+pub fn filter_queued_events<F: FnMut(&Event) -> bool>(callback: F) {
+    let mut filter = QueuedFilter {
+        callback,
+        panic: None,
+    };
+    let data: *mut QueuedFilter<F> = &mut filter;
+    unsafe { bind::SDL_FilterEvents(Some(filter_queued_events_wrap_handler::<F>), data.cast()) }
+    if let Some(payload) = filter.panic {
+        resume_unwind(payload);
+    }
+}
+
+unsafe extern "C" fn filter_queued_events_wrap_handler<F: FnMut(&Event) -> bool>(
+    userdata: *mut c_void,
+    event: *mut bind::SDL_Event,
+) -> c_int {
+    let filter = &mut *userdata.cast::<QueuedFilter<F>>();
+    if filter.panic.is_some() {
+        return 1;
+    }
+    let callback = &mut filter.callback;
+    let passes = match catch_unwind(AssertUnwindSafe(|| call_filter(callback, &*event))) {
+        Ok(passes) => passes,
+        Err(payload) => {
+            filter.panic = Some(payload);
+            true
+        }
+    };
+    if !passes {
+        free_discarded_event(&*event);
+    }
+    passes as c_int
+}
@@ -6,16 +6,23 @@ use std::{
     ptr::NonNull,
 };
 
-use crate::{bind, Result, Sdl, SdlError};
+use crate::{bind, color::Rgb, event::sensor::SensorKind, Result, Sdl, SdlError};
 
-use self::{axis::Axis, button::Button, map::MapInput};
+use self::{
+    axis::Axis, button::Button, kind::ControllerKind, map::MapInput, touchpad::TouchpadFinger,
+};
 
 pub mod axis;
 pub mod button;
 pub mod event;
+pub mod kind;
 pub mod map;
+pub mod mapping;
+pub mod registry;
+pub mod touchpad;
 
 /// A logical game controller manages binding of the physical devices.
+#[derive(PartialEq, Eq)]
 pub struct GameController {
     pub(in crate::event) ptr: NonNull<bind::SDL_GameController>,
 }
@@ -67,6 +74,229 @@ impl GameController {
             unsafe { bind::SDL_GameControllerGetBindForButton(self.ptr.as_ptr(), button.as_raw()) };
         (ret.bindType != bind::SDL_CONTROLLER_BINDTYPE_NONE).then(|| ret.into())
     }
+
+    /// Returns the current value of the axis. The directions "down" and "right" are positive.
+    #[must_use]
+    pub fn axis(&self, axis: Axis) -> i16 {
+        unsafe { bind::SDL_GameControllerGetAxis(self.ptr.as_ptr(), axis.as_raw()) }
+    }
+
+    /// Returns whether the button is pressed currently.
+    #[must_use]
+    pub fn is_pressed(&self, button: Button) -> bool {
+        unsafe { bind::SDL_GameControllerGetButton(self.ptr.as_ptr(), button.as_raw()) != 0 }
+    }
+
+    /// Returns the type of the game controller.
+    #[must_use]
+    pub fn kind(&self) -> ControllerKind {
+        unsafe { bind::SDL_GameControllerGetType(self.ptr.as_ptr()) }.into()
+    }
+
+    /// Returns the player index of the game controller if set.
+    #[must_use]
+    pub fn player_index(&self) -> Option<u32> {
+        let ret = unsafe { bind::SDL_GameControllerGetPlayerIndex(self.ptr.as_ptr()) };
+        (0 <= ret).then(|| ret as u32)
+    }
+
+    /// Sets the player index of the game controller, or clears if `None`.
+    pub fn set_player_index(&self, index: Option<u32>) {
+        let index = index.map_or(-1, |index| index as _);
+        unsafe { bind::SDL_GameControllerSetPlayerIndex(self.ptr.as_ptr(), index) }
+    }
+
+    /// Returns the serial number of the game controller if available.
+    #[must_use]
+    pub fn serial(&self) -> Option<String> {
+        let ptr = unsafe { bind::SDL_GameControllerGetSerial(self.ptr.as_ptr()) };
+        if ptr.is_null() {
+            return None;
+        }
+        let cstr = unsafe { CStr::from_ptr(ptr) };
+        Some(cstr.to_string_lossy().to_string())
+    }
+
+    /// Returns the USB vendor id of the game controller if available.
+    #[must_use]
+    pub fn vendor_id(&self) -> Option<u16> {
+        let ret = unsafe { bind::SDL_GameControllerGetVendor(self.ptr.as_ptr()) };
+        (ret != 0).then(|| ret)
+    }
+
+    /// Returns the USB product id of the game controller if available.
+    #[must_use]
+    pub fn product_id(&self) -> Option<u16> {
+        let ret = unsafe { bind::SDL_GameControllerGetProduct(self.ptr.as_ptr()) };
+        (ret != 0).then(|| ret)
+    }
+
+    /// Returns the product version of the game controller if available.
+    #[must_use]
+    pub fn product_version(&self) -> Option<u16> {
+        let ret = unsafe { bind::SDL_GameControllerGetProductVersion(self.ptr.as_ptr()) };
+        (ret != 0).then(|| ret)
+    }
+
+    /// Starts the rumble effect for `duration_ms` milliseconds, with the intensity of the low and high frequency motors. Calling this again overrides the previous effect, and zero intensities stop it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(UnsupportedFeature)` if the game controller does not support rumble.
+    pub fn rumble(&self, low_frequency: u16, high_frequency: u16, duration_ms: u32) -> Result<()> {
+        let ret = unsafe {
+            bind::SDL_GameControllerRumble(
+                self.ptr.as_ptr(),
+                low_frequency,
+                high_frequency,
+                duration_ms,
+            )
+        };
+        if ret != 0 {
+            return Err(SdlError::UnsupportedFeature);
+        }
+        Ok(())
+    }
+
+    /// Starts the rumble effect in the triggers for `duration_ms` milliseconds, with the intensity of the left and right motors. Calling this again overrides the previous effect, and zero intensities stop it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(UnsupportedFeature)` if the game controller does not support trigger rumble.
+    pub fn rumble_triggers(&self, left: u16, right: u16, duration_ms: u32) -> Result<()> {
+        let ret = unsafe {
+            bind::SDL_GameControllerRumbleTriggers(self.ptr.as_ptr(), left, right, duration_ms)
+        };
+        if ret != 0 {
+            return Err(SdlError::UnsupportedFeature);
+        }
+        Ok(())
+    }
+
+    /// Returns whether the game controller has a LED.
+    #[must_use]
+    pub fn has_led(&self) -> bool {
+        unsafe { bind::SDL_GameControllerHasLED(self.ptr.as_ptr()) == bind::SDL_TRUE }
+    }
+
+    /// Sets the color of the LED on the game controller.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(UnsupportedFeature)` if the game controller does not have a LED.
+    pub fn set_led(&self, color: Rgb) -> Result<()> {
+        let ret =
+            unsafe { bind::SDL_GameControllerSetLED(self.ptr.as_ptr(), color.r, color.g, color.b) };
+        if ret != 0 {
+            return Err(SdlError::UnsupportedFeature);
+        }
+        Ok(())
+    }
+
+    /// Returns whether the game controller has the sensor.
+    #[must_use]
+    pub fn has_sensor(&self, sensor: SensorKind) -> bool {
+        unsafe {
+            bind::SDL_GameControllerHasSensor(self.ptr.as_ptr(), sensor.as_raw()) == bind::SDL_TRUE
+        }
+    }
+
+    /// Enables or disables the sensor to report [`event::ControllerEvent::SensorUpdated`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(UnsupportedFeature)` if the game controller does not have the sensor.
+    pub fn set_sensor_enabled(&self, sensor: SensorKind, enabled: bool) -> Result<()> {
+        let ret = unsafe {
+            bind::SDL_GameControllerSetSensorEnabled(
+                self.ptr.as_ptr(),
+                sensor.as_raw(),
+                if enabled {
+                    bind::SDL_TRUE
+                } else {
+                    bind::SDL_FALSE
+                },
+            )
+        };
+        if ret != 0 {
+            return Err(SdlError::UnsupportedFeature);
+        }
+        Ok(())
+    }
+
+    /// Returns whether the sensor is enabled.
+    #[must_use]
+    pub fn is_sensor_enabled(&self, sensor: SensorKind) -> bool {
+        unsafe {
+            bind::SDL_GameControllerIsSensorEnabled(self.ptr.as_ptr(), sensor.as_raw())
+                == bind::SDL_TRUE
+        }
+    }
+
+    /// Returns the data rate of the sensor in Hz, or `0.0` if unavailable.
+    #[must_use]
+    pub fn sensor_data_rate(&self, sensor: SensorKind) -> f32 {
+        unsafe { bind::SDL_GameControllerGetSensorDataRate(self.ptr.as_ptr(), sensor.as_raw()) }
+    }
+
+    /// Returns the current values of the sensor, the meaning depends on the kind of the sensor.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the sensor is not available or not enabled.
+    pub fn sensor_data(&self, sensor: SensorKind) -> Result<[f32; 3]> {
+        let mut data = [0.0; 3];
+        let ret = unsafe {
+            bind::SDL_GameControllerGetSensorData(
+                self.ptr.as_ptr(),
+                sensor.as_raw(),
+                data.as_mut_ptr(),
+                data.len() as _,
+            )
+        };
+        if ret != 0 {
+            return Err(SdlError::Others { msg: Sdl::error() });
+        }
+        Ok(data)
+    }
+
+    /// Returns the numbers of the touchpads on the game controller.
+    #[must_use]
+    pub fn touchpads(&self) -> u32 {
+        unsafe { bind::SDL_GameControllerGetNumTouchpads(self.ptr.as_ptr()) as u32 }
+    }
+
+    /// Returns the numbers of the fingers supported on the touchpad.
+    #[must_use]
+    pub fn touchpad_fingers(&self, touchpad: u32) -> u32 {
+        unsafe {
+            bind::SDL_GameControllerGetNumTouchpadFingers(self.ptr.as_ptr(), touchpad as _) as u32
+        }
+    }
+
+    /// Returns the state of the finger on the touchpad, or `None` if the touchpad or finger does not exist.
+    #[must_use]
+    pub fn touchpad_finger(&self, touchpad: u32, finger: u32) -> Option<TouchpadFinger> {
+        let mut state = 0;
+        let (mut x, mut y, mut pressure) = (0.0, 0.0, 0.0);
+        let ret = unsafe {
+            bind::SDL_GameControllerGetTouchpadFinger(
+                self.ptr.as_ptr(),
+                touchpad as _,
+                finger as _,
+                &mut state,
+                &mut x,
+                &mut y,
+                &mut pressure,
+            )
+        };
+        (ret == 0).then(|| TouchpadFinger {
+            is_down: state as u32 == bind::SDL_PRESSED,
+            x,
+            y,
+            pressure,
+        })
+    }
 }
 
 /// All of recognized game controllers at initialized.
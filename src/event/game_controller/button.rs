@@ -57,6 +57,7 @@ impl Button {
             bind::SDL_CONTROLLER_BUTTON_LEFTSTICK => Button::LeftStick,
             bind::SDL_CONTROLLER_BUTTON_RIGHTSTICK => Button::RightStick,
             bind::SDL_CONTROLLER_BUTTON_LEFTSHOULDER => Button::LeftShoulder,
+            bind::SDL_CONTROLLER_BUTTON_RIGHTSHOULDER => Button::RightShoulder,
             bind::SDL_CONTROLLER_BUTTON_DPAD_DOWN => Button::LeftFour(FourButton::Down),
             bind::SDL_CONTROLLER_BUTTON_DPAD_RIGHT => Button::LeftFour(FourButton::Right),
             bind::SDL_CONTROLLER_BUTTON_DPAD_LEFT => Button::LeftFour(FourButton::Left),
@@ -68,14 +69,14 @@ impl Button {
 
     pub(super) fn as_raw(self) -> bind::SDL_GameControllerButton {
         match self {
-            Button::LeftFour(FourButton::Up) => bind::SDL_CONTROLLER_BUTTON_Y,
-            Button::LeftFour(FourButton::Right) => bind::SDL_CONTROLLER_BUTTON_B,
-            Button::LeftFour(FourButton::Down) => bind::SDL_CONTROLLER_BUTTON_A,
-            Button::LeftFour(FourButton::Left) => bind::SDL_CONTROLLER_BUTTON_X,
-            Button::RightFour(FourButton::Up) => bind::SDL_CONTROLLER_BUTTON_DPAD_UP,
-            Button::RightFour(FourButton::Right) => bind::SDL_CONTROLLER_BUTTON_DPAD_RIGHT,
-            Button::RightFour(FourButton::Down) => bind::SDL_CONTROLLER_BUTTON_DPAD_DOWN,
-            Button::RightFour(FourButton::Left) => bind::SDL_CONTROLLER_BUTTON_DPAD_LEFT,
+            Button::LeftFour(FourButton::Up) => bind::SDL_CONTROLLER_BUTTON_DPAD_UP,
+            Button::LeftFour(FourButton::Right) => bind::SDL_CONTROLLER_BUTTON_DPAD_RIGHT,
+            Button::LeftFour(FourButton::Down) => bind::SDL_CONTROLLER_BUTTON_DPAD_DOWN,
+            Button::LeftFour(FourButton::Left) => bind::SDL_CONTROLLER_BUTTON_DPAD_LEFT,
+            Button::RightFour(FourButton::Up) => bind::SDL_CONTROLLER_BUTTON_Y,
+            Button::RightFour(FourButton::Right) => bind::SDL_CONTROLLER_BUTTON_B,
+            Button::RightFour(FourButton::Down) => bind::SDL_CONTROLLER_BUTTON_A,
+            Button::RightFour(FourButton::Left) => bind::SDL_CONTROLLER_BUTTON_X,
             Button::Back => bind::SDL_CONTROLLER_BUTTON_BACK,
             Button::Guide => bind::SDL_CONTROLLER_BUTTON_GUIDE,
             Button::Start => bind::SDL_CONTROLLER_BUTTON_START,
@@ -119,3 +120,41 @@ impl FromStr for Button {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn raw_round_trip() {
+        let fours = [
+            FourButton::Up,
+            FourButton::Right,
+            FourButton::Down,
+            FourButton::Left,
+        ];
+        let buttons = fours
+            .iter()
+            .flat_map(|&four| [Button::LeftFour(four), Button::RightFour(four)])
+            .chain([
+                Button::Back,
+                Button::Guide,
+                Button::Start,
+                Button::LeftStick,
+                Button::RightStick,
+                Button::LeftShoulder,
+                Button::RightShoulder,
+            ]);
+        for button in buttons {
+            assert_eq!(Button::from_raw(button.as_raw()), Some(button));
+        }
+        assert_eq!(
+            Button::LeftFour(FourButton::Down).as_raw(),
+            bind::SDL_CONTROLLER_BUTTON_DPAD_DOWN
+        );
+        assert_eq!(
+            Button::RightFour(FourButton::Down).as_raw(),
+            bind::SDL_CONTROLLER_BUTTON_A
+        );
+    }
+}
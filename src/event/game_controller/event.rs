@@ -5,7 +5,7 @@ use std::marker::PhantomData;
 use super::{axis::Axis, button::Button};
 use crate::{
     bind,
-    event::joystick::{Joystick, JoystickId},
+    event::{joystick::JoystickId, sensor::SensorKind, touch::FingerEventKind},
     EnumInt,
 };
 
@@ -38,8 +38,8 @@ pub enum ControllerEvent<'joystick> {
     DeviceAdded {
         /// When this event occurred.
         timestamp: u32,
-        /// An added joystick.
-        joystick: Joystick,
+        /// The device index of the added joystick, not the instance id. Use a registry such as [`super::registry::ControllerRegistry`] to open the device.
+        device_index: u32,
     },
     /// The game controller was removed.
     DeviceRemoved {
@@ -55,6 +55,36 @@ pub enum ControllerEvent<'joystick> {
         /// The id of the remapped joystick.
         id: JoystickId<'joystick>,
     },
+    /// A finger was touched, moved or released on a touchpad.
+    Touchpad {
+        /// When this event occurred.
+        timestamp: u32,
+        /// The id of the joystick having the touchpad.
+        id: JoystickId<'joystick>,
+        /// The kind of the event.
+        kind: FingerEventKind,
+        /// The index of the touchpad.
+        touchpad: i32,
+        /// The index of the finger on the touchpad.
+        finger: i32,
+        /// The normalized x coord of the finger, in `0.0..=1.0`.
+        x: f32,
+        /// The normalized y coord of the finger, in `0.0..=1.0`.
+        y: f32,
+        /// The normalized pressure of the finger, in `0.0..=1.0`.
+        pressure: f32,
+    },
+    /// A sensor on the game controller was updated.
+    SensorUpdated {
+        /// When this event occurred.
+        timestamp: u32,
+        /// The id of the joystick having the sensor.
+        id: JoystickId<'joystick>,
+        /// The kind of the sensor.
+        sensor: SensorKind,
+        /// The updated values, the meaning depends on the kind of the sensor.
+        data: [f32; 3],
+    },
 }
 
 impl From<bind::SDL_ControllerAxisEvent> for ControllerEvent<'_> {
@@ -94,7 +124,7 @@ impl From<bind::SDL_ControllerDeviceEvent> for ControllerEvent<'_> {
         match raw.type_ as EnumInt {
             bind::SDL_CONTROLLERDEVICEADDED => Self::DeviceAdded {
                 timestamp: raw.timestamp,
-                joystick: Joystick::from_id(id).unwrap(),
+                device_index: raw.which as u32,
             },
             bind::SDL_CONTROLLERDEVICEREMOVED => Self::DeviceRemoved {
                 timestamp: raw.timestamp,
@@ -108,3 +138,40 @@ impl From<bind::SDL_ControllerDeviceEvent> for ControllerEvent<'_> {
         }
     }
 }
+
+impl From<bind::SDL_ControllerTouchpadEvent> for ControllerEvent<'_> {
+    fn from(raw: bind::SDL_ControllerTouchpadEvent) -> Self {
+        Self::Touchpad {
+            timestamp: raw.timestamp,
+            id: JoystickId {
+                id: raw.which as u32,
+                _phantom: PhantomData,
+            },
+            kind: match raw.type_ as EnumInt {
+                bind::SDL_CONTROLLERTOUCHPADDOWN => FingerEventKind::Down,
+                bind::SDL_CONTROLLERTOUCHPADMOTION => FingerEventKind::Motion,
+                bind::SDL_CONTROLLERTOUCHPADUP => FingerEventKind::Up,
+                _ => unreachable!(),
+            },
+            touchpad: raw.touchpad,
+            finger: raw.finger,
+            x: raw.x,
+            y: raw.y,
+            pressure: raw.pressure,
+        }
+    }
+}
+
+impl From<bind::SDL_ControllerSensorEvent> for ControllerEvent<'_> {
+    fn from(raw: bind::SDL_ControllerSensorEvent) -> Self {
+        Self::SensorUpdated {
+            timestamp: raw.timestamp,
+            id: JoystickId {
+                id: raw.which as u32,
+                _phantom: PhantomData,
+            },
+            sensor: SensorKind::from_raw(raw.sensor as bind::SDL_SensorType),
+            data: raw.data,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+//! A representation of the type of the game controller.
+
+use crate::bind;
+
+/// A type of a game controller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ControllerKind {
+    /// The type is unknown.
+    Unknown,
+    /// An Xbox 360 controller.
+    Xbox360,
+    /// An Xbox One controller.
+    XboxOne,
+    /// A PlayStation 3 controller.
+    Ps3,
+    /// A PlayStation 4 controller.
+    Ps4,
+    /// A Nintendo Switch Pro controller.
+    NintendoSwitchPro,
+    /// A virtual controller.
+    Virtual,
+    /// A PlayStation 5 controller.
+    Ps5,
+    /// An Amazon Luna controller.
+    AmazonLuna,
+    /// A Google Stadia controller.
+    GoogleStadia,
+}
+
+impl From<bind::SDL_GameControllerType> for ControllerKind {
+    fn from(raw: bind::SDL_GameControllerType) -> Self {
+        match raw {
+            bind::SDL_CONTROLLER_TYPE_XBOX360 => ControllerKind::Xbox360,
+            bind::SDL_CONTROLLER_TYPE_XBOXONE => ControllerKind::XboxOne,
+            bind::SDL_CONTROLLER_TYPE_PS3 => ControllerKind::Ps3,
+            bind::SDL_CONTROLLER_TYPE_PS4 => ControllerKind::Ps4,
+            bind::SDL_CONTROLLER_TYPE_NINTENDO_SWITCH_PRO => ControllerKind::NintendoSwitchPro,
+            bind::SDL_CONTROLLER_TYPE_VIRTUAL => ControllerKind::Virtual,
+            bind::SDL_CONTROLLER_TYPE_PS5 => ControllerKind::Ps5,
+            bind::SDL_CONTROLLER_TYPE_AMAZON_LUNA => ControllerKind::AmazonLuna,
+            bind::SDL_CONTROLLER_TYPE_GOOGLE_STADIA => ControllerKind::GoogleStadia,
+            _ => ControllerKind::Unknown,
+        }
+    }
+}
@@ -0,0 +1,475 @@
+//! A structured representation of the mapping string for game controllers, and generating it from the joystick inputs.
+
+use std::str::FromStr;
+
+use crate::event::joystick::{event::JoystickEvent, guid::Guid, hat::PovHat, Joystick};
+
+use super::{
+    axis::Axis,
+    button::{Button, FourButton},
+    map::MapInput,
+};
+
+/// An error to tell the failure on parsing [`ControllerMapping`] from string, showing the invalid part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseMappingError {
+    text: String,
+}
+
+impl std::fmt::Display for ParseMappingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid mapping: {}", self.text)
+    }
+}
+
+impl std::error::Error for ParseMappingError {}
+
+/// A range of an axis to be used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AxisRange {
+    /// The full range of the axis.
+    Full,
+    /// The positive half of the axis.
+    Positive,
+    /// The negative half of the axis.
+    Negative,
+}
+
+impl AxisRange {
+    fn prefix(self) -> &'static str {
+        match self {
+            AxisRange::Full => "",
+            AxisRange::Positive => "+",
+            AxisRange::Negative => "-",
+        }
+    }
+
+    fn split(s: &str) -> (Self, &str) {
+        if let Some(rest) = s.strip_prefix('+') {
+            (AxisRange::Positive, rest)
+        } else if let Some(rest) = s.strip_prefix('-') {
+            (AxisRange::Negative, rest)
+        } else {
+            (AxisRange::Full, s)
+        }
+    }
+}
+
+/// A logical input of the game controller to be mapped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MappingTarget {
+    /// A button of the game controller.
+    Button(Button),
+    /// An axis of the game controller, or its half if the range is not full.
+    Axis(Axis, AxisRange),
+}
+
+impl std::fmt::Display for MappingTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MappingTarget::Button(button) => write!(f, "{}", button),
+            MappingTarget::Axis(axis, range) => write!(f, "{}{}", range.prefix(), axis),
+        }
+    }
+}
+
+/// A binding from the physical input to the logical input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MappingBinding {
+    /// The logical input of the game controller.
+    pub target: MappingTarget,
+    /// The physical input of the joystick.
+    pub input: MapInput,
+    /// The range of the physical axis to be used, only for [`MapInput::Axis`].
+    pub input_range: AxisRange,
+    /// Whether the physical axis is inverted, only for [`MapInput::Axis`].
+    pub inverted: bool,
+}
+
+impl MappingBinding {
+    fn input_to_string(&self) -> String {
+        match self.input {
+            MapInput::Button { index } => format!("b{}", index),
+            MapInput::Axis { index } => format!(
+                "{}a{}{}",
+                self.input_range.prefix(),
+                index,
+                if self.inverted { "~" } else { "" }
+            ),
+            MapInput::Hat { index, mask } => format!("h{}.{}", index, mask),
+        }
+    }
+
+    fn parse(target: MappingTarget, s: &str) -> Option<Self> {
+        let (input_range, rest) = AxisRange::split(s);
+        let (rest, inverted) = match rest.strip_suffix('~') {
+            Some(rest) => (rest, true),
+            None => (rest, false),
+        };
+        let input = if let Some(index) = rest.strip_prefix('b') {
+            MapInput::Button {
+                index: index.parse().ok()?,
+            }
+        } else if let Some(index) = rest.strip_prefix('a') {
+            MapInput::Axis {
+                index: index.parse().ok()?,
+            }
+        } else if let Some(hat) = rest.strip_prefix('h') {
+            let (index, mask) = hat.split_once('.')?;
+            MapInput::Hat {
+                index: index.parse().ok()?,
+                mask: mask.parse().ok()?,
+            }
+        } else {
+            return None;
+        };
+        let is_axis = matches!(input, MapInput::Axis { .. });
+        if !is_axis && (input_range != AxisRange::Full || inverted) {
+            return None;
+        }
+        Some(Self {
+            target,
+            input,
+            input_range,
+            inverted,
+        })
+    }
+}
+
+/// A mapping for a game controller, which can be converted from/into the mapping string by [`FromStr`] and [`std::fmt::Display`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ControllerMapping {
+    /// The GUID of the joystick.
+    pub guid: Guid,
+    /// The name of the game controller.
+    pub name: String,
+    /// The bindings of the inputs.
+    pub bindings: Vec<MappingBinding>,
+    /// The platform which this mapping is for, such as `Windows`, `Mac OS X` and `Linux`.
+    pub platform: Option<String>,
+    /// The other fields not recognized by this, as the pairs of the key and value.
+    pub others: Vec<(String, String)>,
+}
+
+impl ControllerMapping {
+    /// Returns the physical input bound to the target if exists.
+    #[must_use]
+    pub fn binding_for(&self, target: MappingTarget) -> Option<&MappingBinding> {
+        self.bindings
+            .iter()
+            .find(|binding| binding.target == target)
+    }
+}
+
+impl std::fmt::Display for ControllerMapping {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},", self.guid, self.name)?;
+        for binding in &self.bindings {
+            write!(f, "{}:{},", binding.target, binding.input_to_string())?;
+        }
+        for (key, value) in &self.others {
+            write!(f, "{}:{},", key, value)?;
+        }
+        if let Some(platform) = &self.platform {
+            write!(f, "platform:{},", platform)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ControllerMapping {
+    type Err = ParseMappingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = |text: &str| ParseMappingError { text: text.into() };
+        let mut fields = s.trim().split(',');
+        let guid_field = fields.next().ok_or_else(|| err(s))?;
+        let guid = guid_field.parse().map_err(|_| err(guid_field))?;
+        let name = fields.next().ok_or_else(|| err(s))?.into();
+        let mut mapping = Self {
+            guid,
+            name,
+            bindings: vec![],
+            platform: None,
+            others: vec![],
+        };
+        for field in fields.filter(|field| !field.is_empty()) {
+            let (key, value) = field.split_once(':').ok_or_else(|| err(field))?;
+            if key == "platform" {
+                mapping.platform = Some(value.into());
+                continue;
+            }
+            let (range, name) = AxisRange::split(key);
+            let target = if let Ok(axis) = name.parse() {
+                MappingTarget::Axis(axis, range)
+            } else if let (AxisRange::Full, Ok(button)) = (range, name.parse()) {
+                MappingTarget::Button(button)
+            } else {
+                mapping.others.push((key.into(), value.into()));
+                continue;
+            };
+            let binding = MappingBinding::parse(target, value).ok_or_else(|| err(field))?;
+            mapping.bindings.push(binding);
+        }
+        Ok(mapping)
+    }
+}
+
+/// A generator of [`ControllerMapping`] from the inputs on the joystick. It asks the targets in order, and binds the physical input observed from [`JoystickEvent`] to the current target.
+#[derive(Debug, Clone)]
+pub struct MappingGenerator {
+    instance_id: u32,
+    mapping: ControllerMapping,
+    targets: Vec<MappingTarget>,
+    current: usize,
+    axis_baselines: Vec<i16>,
+}
+
+impl MappingGenerator {
+    /// Constructs a generator to ask all the buttons and axes.
+    #[must_use]
+    pub fn new(joystick: &Joystick) -> Self {
+        let fours = [
+            FourButton::Down,
+            FourButton::Right,
+            FourButton::Left,
+            FourButton::Up,
+        ];
+        let targets = fours
+            .iter()
+            .map(|&four| MappingTarget::Button(Button::RightFour(four)))
+            .chain(
+                [
+                    Button::Back,
+                    Button::Guide,
+                    Button::Start,
+                    Button::LeftStick,
+                    Button::RightStick,
+                    Button::LeftShoulder,
+                    Button::RightShoulder,
+                ]
+                .iter()
+                .map(|&button| MappingTarget::Button(button)),
+            )
+            .chain(
+                [
+                    FourButton::Up,
+                    FourButton::Down,
+                    FourButton::Left,
+                    FourButton::Right,
+                ]
+                .iter()
+                .map(|&four| MappingTarget::Button(Button::LeftFour(four))),
+            )
+            .chain(
+                [
+                    Axis::LeftX,
+                    Axis::LeftY,
+                    Axis::RightX,
+                    Axis::RightY,
+                    Axis::TriggerLeft,
+                    Axis::TriggerRight,
+                ]
+                .iter()
+                .map(|&axis| MappingTarget::Axis(axis, AxisRange::Full)),
+            )
+            .collect();
+        Self::with_targets(joystick, targets)
+    }
+
+    /// Constructs a generator to ask the targets in order.
+    #[must_use]
+    pub fn with_targets(joystick: &Joystick, targets: Vec<MappingTarget>) -> Self {
+        Self {
+            instance_id: joystick.instance_id().id,
+            mapping: ControllerMapping {
+                guid: joystick.guid(),
+                name: joystick.name().into_owned(),
+                bindings: vec![],
+                platform: None,
+                others: vec![],
+            },
+            targets,
+            current: 0,
+            axis_baselines: joystick.axes().0.iter().map(|axis| axis.state()).collect(),
+        }
+    }
+
+    /// Returns the target asked currently, or `None` if finished.
+    #[must_use]
+    pub fn current_target(&self) -> Option<MappingTarget> {
+        self.targets.get(self.current).copied()
+    }
+
+    /// Returns whether all the targets were asked.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.targets.len() <= self.current
+    }
+
+    /// Skips the current target without binding.
+    pub fn skip(&mut self) {
+        self.current += 1;
+    }
+
+    /// Feeds the event, and returns whether the current target was bound by it. The events from the other joysticks are ignored.
+    pub fn feed(&mut self, event: &JoystickEvent) -> bool {
+        const THRESHOLD: i32 = 16384;
+        let target = match self.current_target() {
+            Some(target) => target,
+            None => return false,
+        };
+        let (input, input_range) = match event {
+            JoystickEvent::Button {
+                id,
+                button,
+                is_pressed: true,
+                ..
+            } if id.id == self.instance_id => (
+                MapInput::Button {
+                    index: button.index(),
+                },
+                AxisRange::Full,
+            ),
+            JoystickEvent::Hat { id, hat, value, .. }
+                if id.id == self.instance_id && *value != PovHat::CENTERED =>
+            {
+                (
+                    MapInput::Hat {
+                        index: hat.index(),
+                        mask: u32::from(value.bits()),
+                    },
+                    AxisRange::Full,
+                )
+            }
+            JoystickEvent::Axis {
+                id, axis, value, ..
+            } if id.id == self.instance_id => {
+                let index = axis.index();
+                let baseline = self
+                    .axis_baselines
+                    .get(index as usize)
+                    .copied()
+                    .unwrap_or(0);
+                let diff = i32::from(*value) - i32::from(baseline);
+                if diff.abs() < THRESHOLD {
+                    return false;
+                }
+                let range = match target {
+                    MappingTarget::Button(_) if diff < 0 => AxisRange::Negative,
+                    MappingTarget::Button(_) => AxisRange::Positive,
+                    MappingTarget::Axis(..) => AxisRange::Full,
+                };
+                (MapInput::Axis { index }, range)
+            }
+            _ => return false,
+        };
+        let already_bound = self
+            .mapping
+            .bindings
+            .iter()
+            .any(|binding| binding.input == input && binding.input_range == input_range);
+        if already_bound {
+            return false;
+        }
+        self.mapping.bindings.push(MappingBinding {
+            target,
+            input,
+            input_range,
+            inverted: false,
+        });
+        self.current += 1;
+        true
+    }
+
+    /// Returns the mapping generated so far.
+    #[must_use]
+    pub fn mapping(&self) -> &ControllerMapping {
+        &self.mapping
+    }
+
+    /// Converts into the generated mapping.
+    #[must_use]
+    pub fn into_mapping(self) -> ControllerMapping {
+        self.mapping
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const XBOX_360_LINUX: &str = "030000005e0400008e02000014010000,Xbox 360 Controller,a:b0,b:b1,back:b6,dpdown:h0.4,dpleft:h0.8,dpright:h0.2,dpup:h0.1,guide:b8,leftshoulder:b4,leftstick:b9,lefttrigger:a2,leftx:a0,lefty:a1,rightshoulder:b5,rightstick:b10,righttrigger:a5,rightx:a3,righty:a4,start:b7,x:b2,y:b3,platform:Linux,";
+
+    fn input_of(mapping: &ControllerMapping, target: MappingTarget) -> Option<MapInput> {
+        mapping.binding_for(target).map(|binding| binding.input)
+    }
+
+    #[test]
+    fn parse_and_display_sdl_mapping() {
+        let mapping: ControllerMapping = XBOX_360_LINUX.parse().unwrap();
+        assert_eq!(mapping.name, "Xbox 360 Controller");
+        assert_eq!(mapping.platform.as_deref(), Some("Linux"));
+        assert!(mapping.others.is_empty());
+        assert_eq!(mapping.bindings.len(), 21);
+        assert_eq!(
+            input_of(
+                &mapping,
+                MappingTarget::Button(Button::RightFour(FourButton::Down))
+            ),
+            Some(MapInput::Button { index: 0 })
+        );
+        assert_eq!(
+            input_of(
+                &mapping,
+                MappingTarget::Button(Button::LeftFour(FourButton::Down))
+            ),
+            Some(MapInput::Hat { index: 0, mask: 4 })
+        );
+        assert_eq!(
+            input_of(&mapping, MappingTarget::Button(Button::RightShoulder)),
+            Some(MapInput::Button { index: 5 })
+        );
+        assert_eq!(
+            input_of(
+                &mapping,
+                MappingTarget::Axis(Axis::TriggerRight, AxisRange::Full)
+            ),
+            Some(MapInput::Axis { index: 5 })
+        );
+        assert_eq!(mapping.to_string(), XBOX_360_LINUX);
+    }
+
+    #[test]
+    fn round_trip_half_axes_and_unknown_fields() {
+        let text = "03000000de280000ff11000001000000,Steam Virtual Gamepad,a:b0,-leftx:h0.8,+leftx:h0.2,lefty:a1~,righttrigger:+a5,leftshoulder:-a2,crc:4bd2,platform:Windows,";
+        let mapping: ControllerMapping = text.parse().unwrap();
+        assert_eq!(mapping.others, vec![("crc".into(), "4bd2".into())]);
+        let left_x = mapping
+            .binding_for(MappingTarget::Axis(Axis::LeftX, AxisRange::Negative))
+            .unwrap();
+        assert_eq!(left_x.input, MapInput::Hat { index: 0, mask: 8 });
+        let left_y = mapping
+            .binding_for(MappingTarget::Axis(Axis::LeftY, AxisRange::Full))
+            .unwrap();
+        assert!(left_y.inverted);
+        let shoulder = mapping
+            .binding_for(MappingTarget::Button(Button::LeftShoulder))
+            .unwrap();
+        assert_eq!(shoulder.input_range, AxisRange::Negative);
+        assert_eq!(mapping.to_string(), text);
+        assert_eq!(
+            mapping.to_string().parse::<ControllerMapping>(),
+            Ok(mapping)
+        );
+    }
+
+    #[test]
+    fn invalid_guid_is_rejected() {
+        let guid = "030000005e0400008e0200001401\u{0}000";
+        assert_eq!(guid.len(), 32);
+        assert!(guid.parse::<Guid>().is_err());
+        let text = format!("{},Broken,a:b0,", guid);
+        assert!(text.parse::<ControllerMapping>().is_err());
+    }
+}
@@ -0,0 +1,186 @@
+//! A registry of the game controllers following the connection and disconnection.
+
+use std::{
+    cell::{Ref, RefCell},
+    collections::BTreeMap,
+    marker::PhantomData,
+    os::raw::c_int,
+    ptr::NonNull,
+    rc::Rc,
+};
+
+use crate::{
+    bind,
+    event::{
+        joystick::{
+            registry::{ConnectCallback, DisconnectCallback},
+            JoystickId,
+        },
+        EventBox, Propagation,
+    },
+};
+
+use super::{event::ControllerEvent, GameController};
+
+struct Inner<'video> {
+    controllers: BTreeMap<u32, GameController>,
+    connect_callbacks: Vec<ConnectCallback<'video, GameController>>,
+    disconnect_callbacks: Vec<DisconnectCallback<'video>>,
+}
+
+impl Inner<'_> {
+    fn open_new_devices(&mut self) {
+        let num_joysticks = unsafe { bind::SDL_NumJoysticks() };
+        for index in 0..num_joysticks {
+            self.open_device(index as u32);
+        }
+    }
+
+    fn open_device(&mut self, device_index: u32) -> Option<u32> {
+        let index = device_index as c_int;
+        if unsafe { bind::SDL_IsGameController(index) } == 0 {
+            return None;
+        }
+        let id = unsafe { bind::SDL_JoystickGetDeviceInstanceID(index) };
+        if id < 0 || self.controllers.contains_key(&(id as u32)) {
+            return None;
+        }
+        let ptr = unsafe { bind::SDL_GameControllerOpen(index) };
+        let controller = GameController {
+            ptr: NonNull::new(ptr)?,
+        };
+        self.controllers.insert(id as u32, controller);
+        Some(id as u32)
+    }
+}
+
+/// A registry of the game controllers, which opens and closes the game controllers automatically by the device events from [`EventBox`].
+pub struct ControllerRegistry<'video> {
+    inner: Rc<RefCell<Inner<'video>>>,
+}
+
+impl std::fmt::Debug for ControllerRegistry<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ControllerRegistry")
+            .field("controllers", &self.inner.borrow().controllers)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'video> ControllerRegistry<'video> {
+    /// Constructs a registry with the game controllers connected currently, and registers the handler into the event box.
+    #[must_use]
+    pub fn new(event_box: &mut EventBox<'video>) -> Self {
+        unsafe {
+            bind::SDL_InitSubSystem(bind::SDL_INIT_GAMECONTROLLER);
+        }
+        let inner = Rc::new(RefCell::new(Inner {
+            controllers: BTreeMap::new(),
+            connect_callbacks: vec![],
+            disconnect_callbacks: vec![],
+        }));
+        inner.borrow_mut().open_new_devices();
+        let weak = Rc::downgrade(&inner);
+        event_box.handle_controller(Box::new(move |event| {
+            if let Some(inner) = weak.upgrade() {
+                match event {
+                    ControllerEvent::DeviceAdded { device_index, .. } => {
+                        Self::on_added(&inner, *device_index);
+                    }
+                    ControllerEvent::DeviceRemoved { id, .. } => Self::on_removed(&inner, id.id),
+                    _ => {}
+                }
+            }
+            Propagation::Continue
+        }));
+        Self { inner }
+    }
+
+    fn on_added(inner: &RefCell<Inner<'video>>, device_index: u32) {
+        let added = inner.borrow_mut().open_device(device_index);
+        let id = match added {
+            Some(id) => id,
+            None => return,
+        };
+        let mut callbacks = std::mem::take(&mut inner.borrow_mut().connect_callbacks);
+        {
+            let inner = inner.borrow();
+            let controller = &inner.controllers[&id];
+            for callback in &mut callbacks {
+                callback(controller);
+            }
+        }
+        let mut inner = inner.borrow_mut();
+        callbacks.append(&mut inner.connect_callbacks);
+        inner.connect_callbacks = callbacks;
+    }
+
+    fn on_removed(inner: &RefCell<Inner<'video>>, id: u32) {
+        let removed = inner.borrow_mut().controllers.remove(&id);
+        if let Some(controller) = removed {
+            unsafe { bind::SDL_GameControllerClose(controller.ptr.as_ptr()) }
+            let mut callbacks = std::mem::take(&mut inner.borrow_mut().disconnect_callbacks);
+            for callback in &mut callbacks {
+                callback(JoystickId {
+                    id,
+                    _phantom: PhantomData,
+                });
+            }
+            let mut inner = inner.borrow_mut();
+            callbacks.append(&mut inner.disconnect_callbacks);
+            inner.disconnect_callbacks = callbacks;
+        }
+    }
+
+    /// Registers the callback invoked when a game controller was connected. The game controllers connected already are not notified, so use [`ControllerRegistry::controllers`] for them.
+    pub fn on_connect(&self, callback: ConnectCallback<'video, GameController>) {
+        self.inner.borrow_mut().connect_callbacks.push(callback);
+    }
+
+    /// Registers the callback invoked when a game controller was disconnected.
+    pub fn on_disconnect(&self, callback: DisconnectCallback<'video>) {
+        self.inner.borrow_mut().disconnect_callbacks.push(callback);
+    }
+
+    /// Returns the game controller of the id if it is connected. It is borrowed from the registry, so drop it before handling the next events.
+    #[must_use]
+    pub fn get(&self, id: JoystickId) -> Option<Ref<'_, GameController>> {
+        let inner = self.inner.borrow();
+        inner
+            .controllers
+            .contains_key(&id.id)
+            .then(|| Ref::map(inner, |inner| &inner.controllers[&id.id]))
+    }
+
+    /// Returns the connected game controllers, in the order of connection. They are borrowed from the registry, so drop them before handling the next events.
+    #[must_use]
+    pub fn controllers(&self) -> Vec<Ref<'_, GameController>> {
+        let inner = self.inner.borrow();
+        inner
+            .controllers
+            .keys()
+            .map(|id| Ref::map(Ref::clone(&inner), |inner| &inner.controllers[id]))
+            .collect()
+    }
+
+    /// Returns the numbers of the connected game controllers.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.inner.borrow().controllers.len()
+    }
+
+    /// Returns whether no game controllers are connected.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.inner.borrow().controllers.is_empty()
+    }
+}
+
+impl Drop for ControllerRegistry<'_> {
+    fn drop(&mut self) {
+        for controller in self.inner.borrow().controllers.values() {
+            unsafe { bind::SDL_GameControllerClose(controller.ptr.as_ptr()) }
+        }
+        unsafe { bind::SDL_QuitSubSystem(bind::SDL_INIT_GAMECONTROLLER) }
+    }
+}
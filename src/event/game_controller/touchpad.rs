@@ -0,0 +1,14 @@
+//! A state of fingers on a touchpad of a game controller.
+
+/// A state of a finger on a touchpad, got by [`super::GameController::touchpad_finger`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TouchpadFinger {
+    /// Whether the finger is touching the touchpad.
+    pub is_down: bool,
+    /// The normalized x coord of the finger, in `0.0..=1.0`.
+    pub x: f32,
+    /// The normalized y coord of the finger, in `0.0..=1.0`.
+    pub y: f32,
+    /// The normalized pressure of the finger, in `0.0..=1.0`.
+    pub pressure: f32,
+}
@@ -0,0 +1,65 @@
+//! Iterators to receive events from the event queue.
+
+use super::{Event, EventBox};
+
+/// An iterator that polls all the events in the queue, created by [`EventBox::poll_iter`].
+pub struct PollIter<'event, 'video> {
+    event_box: &'event mut EventBox<'video>,
+}
+
+impl std::fmt::Debug for PollIter<'_, '_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PollIter").finish_non_exhaustive()
+    }
+}
+
+impl<'event, 'video> PollIter<'event, 'video> {
+    pub(super) fn new(event_box: &'event mut EventBox<'video>) -> Self {
+        Self { event_box }
+    }
+}
+
+impl<'video> Iterator for PollIter<'_, 'video> {
+    type Item = Event<'video>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let raw = self.event_box.poll_raw()?;
+            if let Some(event) = self.event_box.decode(raw) {
+                self.event_box.handle_event(&event);
+                return Some(event);
+            }
+        }
+    }
+}
+
+/// An iterator that waits for the next event, created by [`EventBox::wait_iter`].
+pub struct WaitIter<'event, 'video> {
+    event_box: &'event mut EventBox<'video>,
+}
+
+impl std::fmt::Debug for WaitIter<'_, '_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WaitIter").finish_non_exhaustive()
+    }
+}
+
+impl<'event, 'video> WaitIter<'event, 'video> {
+    pub(super) fn new(event_box: &'event mut EventBox<'video>) -> Self {
+        Self { event_box }
+    }
+}
+
+impl<'video> Iterator for WaitIter<'_, 'video> {
+    type Item = Event<'video>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let raw = self.event_box.wait_raw(None)?;
+            if let Some(event) = self.event_box.decode(raw) {
+                self.event_box.handle_event(&event);
+                return Some(event);
+            }
+        }
+    }
+}
@@ -12,8 +12,8 @@ use self::button::Button;
 use self::hat::Hat;
 use self::trackball::Trackball;
 use self::{
-    axis::Axes, button::Buttons, guid::Guid, hat::Hats, power_level::PowerLevel,
-    trackball::Trackballs,
+    axis::Axes, button::Buttons, guid::Guid, hat::Hats, kind::JoystickKind,
+    power_level::PowerLevel, trackball::Trackballs,
 };
 
 use super::game_controller::GameController;
@@ -23,17 +23,26 @@ pub mod button;
 pub mod event;
 pub mod guid;
 pub mod hat;
+pub mod kind;
 pub mod power_level;
+pub mod registry;
 pub mod trackball;
+pub mod virtual_joystick;
 
 /// An index on the input device on the joystick. This is only generated from joystick input events from [`crate::EventBox`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct InputIndex(c_int);
 
+impl InputIndex {
+    pub(crate) fn index(self) -> u32 {
+        self.0 as u32
+    }
+}
+
 /// An id of the joystick. It can be used to share/duplicate Joystick object, but not [`Send`] and [`Sync`] due to the safety.
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct JoystickId<'joystick> {
-    pub(super) id: u32,
+    pub(crate) id: u32,
     pub(super) _phantom: PhantomData<&'joystick Joystick>,
 }
 
@@ -91,6 +100,12 @@ impl Joystick {
         }
     }
 
+    /// Returns the type of the joystick.
+    #[must_use]
+    pub fn kind(&self) -> JoystickKind {
+        unsafe { bind::SDL_JoystickGetType(self.ptr.as_ptr()) }.into()
+    }
+
     /// Returns the power level of the joystick.
     #[must_use]
     pub fn power_level(&self) -> PowerLevel {
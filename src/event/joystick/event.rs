@@ -4,7 +4,7 @@ use std::{marker::PhantomData, os::raw::c_int};
 
 use crate::{bind, EnumInt};
 
-use super::{hat::PovHat, InputIndex, Joystick, JoystickId};
+use super::{hat::PovHat, InputIndex, JoystickId};
 
 /// An event occurs on inputted from a joystick or changed a joystick.
 #[derive(Debug)]
@@ -59,8 +59,8 @@ pub enum JoystickEvent<'joystick> {
     DeviceAdded {
         /// When this event occurred.
         timestamp: u32,
-        /// An added joystick.
-        joystick: Joystick,
+        /// The device index of the added joystick, not the instance id. Use a registry such as [`super::registry::JoystickRegistry`] to open the device.
+        device_index: u32,
     },
     /// The joystick was removed.
     DeviceRemoved {
@@ -123,7 +123,7 @@ impl From<bind::SDL_JoyHatEvent> for JoystickEvent<'_> {
                 _phantom: PhantomData,
             },
             hat: InputIndex(raw.hat as _),
-            value: PovHat::from_bits(raw.value).unwrap(),
+            value: PovHat::from_bits_truncate(raw.value),
         }
     }
 }
@@ -137,7 +137,7 @@ impl From<bind::SDL_JoyDeviceEvent> for JoystickEvent<'_> {
         match raw.type_ as EnumInt {
             bind::SDL_JOYDEVICEADDED => Self::DeviceAdded {
                 timestamp: raw.timestamp,
-                joystick: Joystick::from_id(id).unwrap(),
+                device_index: raw.which as u32,
             },
             bind::SDL_JOYDEVICEREMOVED => Self::DeviceRemoved {
                 timestamp: raw.timestamp,
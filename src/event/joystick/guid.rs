@@ -8,7 +8,7 @@ use std::{
 use crate::bind;
 
 /// A GUID associated with joystick devices.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Guid([u8; 16]);
 
 impl Guid {
@@ -35,38 +35,51 @@ impl std::fmt::Display for Guid {
                 buf.len() as c_int,
             );
         }
-        write!(f, "{}", String::from_utf8_lossy(&buf))
+        let len = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+        write!(f, "{}", String::from_utf8_lossy(&buf[..len]))
     }
 }
 
-/// An error to tell the failure on parsing `Guid` from string, showing that it is invalid length to convert into.
+/// An error to tell the failure on parsing `Guid` from string.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct InvalidLengthError {
-    actual_length: usize,
+pub enum ParseGuidError {
+    /// The length of the string was not 32.
+    InvalidLength {
+        /// The length of the string.
+        actual_length: usize,
+    },
+    /// The string contained a character which is not a hex digit.
+    InvalidDigit,
 }
 
-impl std::fmt::Display for InvalidLengthError {
+impl std::fmt::Display for ParseGuidError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "length must equals to 16 but actual length was {}",
-            self.actual_length
-        )
+        match self {
+            ParseGuidError::InvalidLength { actual_length } => write!(
+                f,
+                "length must equals to 32 but actual length was {}",
+                actual_length
+            ),
+            ParseGuidError::InvalidDigit => write!(f, "string must consist of hex digits"),
+        }
     }
 }
 
-impl std::error::Error for InvalidLengthError {}
+impl std::error::Error for ParseGuidError {}
 
 impl std::str::FromStr for Guid {
-    type Err = InvalidLengthError;
+    type Err = ParseGuidError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.len() != 16 {
-            return Err(InvalidLengthError {
+        if s.len() != 32 {
+            return Err(ParseGuidError::InvalidLength {
                 actual_length: s.len(),
             });
         }
-        let c_str = CString::new(s).expect("invalid string");
+        if !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(ParseGuidError::InvalidDigit);
+        }
+        let c_str = CString::new(s).map_err(|_| ParseGuidError::InvalidDigit)?;
         let raw_guid = unsafe { bind::SDL_JoystickGetGUIDFromString(c_str.as_ptr()) };
         Ok(raw_guid.into())
     }
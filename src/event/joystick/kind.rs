@@ -0,0 +1,62 @@
+//! A representation of the type of the joystick.
+
+use crate::bind;
+
+/// A type of a joystick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JoystickKind {
+    /// The type is unknown.
+    Unknown,
+    /// A game controller.
+    GameController,
+    /// A wheel.
+    Wheel,
+    /// An arcade stick.
+    ArcadeStick,
+    /// A flight stick.
+    FlightStick,
+    /// A dance pad.
+    DancePad,
+    /// A guitar.
+    Guitar,
+    /// A drum kit.
+    DrumKit,
+    /// An arcade pad.
+    ArcadePad,
+    /// A throttle.
+    Throttle,
+}
+
+impl JoystickKind {
+    pub(super) fn as_raw(self) -> bind::SDL_JoystickType {
+        match self {
+            JoystickKind::Unknown => bind::SDL_JOYSTICK_TYPE_UNKNOWN,
+            JoystickKind::GameController => bind::SDL_JOYSTICK_TYPE_GAMECONTROLLER,
+            JoystickKind::Wheel => bind::SDL_JOYSTICK_TYPE_WHEEL,
+            JoystickKind::ArcadeStick => bind::SDL_JOYSTICK_TYPE_ARCADE_STICK,
+            JoystickKind::FlightStick => bind::SDL_JOYSTICK_TYPE_FLIGHT_STICK,
+            JoystickKind::DancePad => bind::SDL_JOYSTICK_TYPE_DANCE_PAD,
+            JoystickKind::Guitar => bind::SDL_JOYSTICK_TYPE_GUITAR,
+            JoystickKind::DrumKit => bind::SDL_JOYSTICK_TYPE_DRUM_KIT,
+            JoystickKind::ArcadePad => bind::SDL_JOYSTICK_TYPE_ARCADE_PAD,
+            JoystickKind::Throttle => bind::SDL_JOYSTICK_TYPE_THROTTLE,
+        }
+    }
+}
+
+impl From<bind::SDL_JoystickType> for JoystickKind {
+    fn from(raw: bind::SDL_JoystickType) -> Self {
+        match raw {
+            bind::SDL_JOYSTICK_TYPE_GAMECONTROLLER => JoystickKind::GameController,
+            bind::SDL_JOYSTICK_TYPE_WHEEL => JoystickKind::Wheel,
+            bind::SDL_JOYSTICK_TYPE_ARCADE_STICK => JoystickKind::ArcadeStick,
+            bind::SDL_JOYSTICK_TYPE_FLIGHT_STICK => JoystickKind::FlightStick,
+            bind::SDL_JOYSTICK_TYPE_DANCE_PAD => JoystickKind::DancePad,
+            bind::SDL_JOYSTICK_TYPE_GUITAR => JoystickKind::Guitar,
+            bind::SDL_JOYSTICK_TYPE_DRUM_KIT => JoystickKind::DrumKit,
+            bind::SDL_JOYSTICK_TYPE_ARCADE_PAD => JoystickKind::ArcadePad,
+            bind::SDL_JOYSTICK_TYPE_THROTTLE => JoystickKind::Throttle,
+            _ => JoystickKind::Unknown,
+        }
+    }
+}
@@ -0,0 +1,182 @@
+//! A registry of the joysticks following the connection and disconnection.
+
+use std::{
+    cell::{Ref, RefCell},
+    collections::BTreeMap,
+    marker::PhantomData,
+    os::raw::c_int,
+    ptr::NonNull,
+    rc::Rc,
+};
+
+use crate::{
+    bind,
+    event::{EventBox, Propagation},
+};
+
+use super::{event::JoystickEvent, Joystick, JoystickId};
+
+/// A callback invoked when a device was connected.
+pub type ConnectCallback<'callback, T> = Box<dyn FnMut(&T) + 'callback>;
+
+/// A callback invoked when a device was disconnected, with the id of it.
+pub type DisconnectCallback<'callback> = Box<dyn FnMut(JoystickId) + 'callback>;
+
+struct Inner<'video> {
+    joysticks: BTreeMap<u32, Joystick>,
+    connect_callbacks: Vec<ConnectCallback<'video, Joystick>>,
+    disconnect_callbacks: Vec<DisconnectCallback<'video>>,
+}
+
+impl Inner<'_> {
+    fn open_new_devices(&mut self) {
+        let num_joysticks = unsafe { bind::SDL_NumJoysticks() };
+        for index in 0..num_joysticks {
+            self.open_device(index as u32);
+        }
+    }
+
+    fn open_device(&mut self, device_index: u32) -> Option<u32> {
+        let id = unsafe { bind::SDL_JoystickGetDeviceInstanceID(device_index as c_int) };
+        if id < 0 || self.joysticks.contains_key(&(id as u32)) {
+            return None;
+        }
+        let ptr = unsafe { bind::SDL_JoystickOpen(device_index as c_int) };
+        let joystick = Joystick {
+            ptr: NonNull::new(ptr)?,
+        };
+        self.joysticks.insert(id as u32, joystick);
+        Some(id as u32)
+    }
+}
+
+/// A registry of the joysticks, which opens and closes the joysticks automatically by the device events from [`EventBox`].
+pub struct JoystickRegistry<'video> {
+    inner: Rc<RefCell<Inner<'video>>>,
+}
+
+impl std::fmt::Debug for JoystickRegistry<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JoystickRegistry")
+            .field("joysticks", &self.inner.borrow().joysticks)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'video> JoystickRegistry<'video> {
+    /// Constructs a registry with the joysticks connected currently, and registers the handler into the event box.
+    #[must_use]
+    pub fn new(event_box: &mut EventBox<'video>) -> Self {
+        unsafe {
+            bind::SDL_InitSubSystem(bind::SDL_INIT_JOYSTICK);
+        }
+        let inner = Rc::new(RefCell::new(Inner {
+            joysticks: BTreeMap::new(),
+            connect_callbacks: vec![],
+            disconnect_callbacks: vec![],
+        }));
+        inner.borrow_mut().open_new_devices();
+        let weak = Rc::downgrade(&inner);
+        event_box.handle_joystick(Box::new(move |event| {
+            if let Some(inner) = weak.upgrade() {
+                match event {
+                    JoystickEvent::DeviceAdded { device_index, .. } => {
+                        Self::on_added(&inner, *device_index);
+                    }
+                    JoystickEvent::DeviceRemoved { id, .. } => Self::on_removed(&inner, id.id),
+                    _ => {}
+                }
+            }
+            Propagation::Continue
+        }));
+        Self { inner }
+    }
+
+    fn on_added(inner: &RefCell<Inner<'video>>, device_index: u32) {
+        let added = inner.borrow_mut().open_device(device_index);
+        let id = match added {
+            Some(id) => id,
+            None => return,
+        };
+        let mut callbacks = std::mem::take(&mut inner.borrow_mut().connect_callbacks);
+        {
+            let inner = inner.borrow();
+            let joystick = &inner.joysticks[&id];
+            for callback in &mut callbacks {
+                callback(joystick);
+            }
+        }
+        let mut inner = inner.borrow_mut();
+        callbacks.append(&mut inner.connect_callbacks);
+        inner.connect_callbacks = callbacks;
+    }
+
+    fn on_removed(inner: &RefCell<Inner<'video>>, id: u32) {
+        let removed = inner.borrow_mut().joysticks.remove(&id);
+        if let Some(joystick) = removed {
+            unsafe { bind::SDL_JoystickClose(joystick.ptr.as_ptr()) }
+            let mut callbacks = std::mem::take(&mut inner.borrow_mut().disconnect_callbacks);
+            for callback in &mut callbacks {
+                callback(JoystickId {
+                    id,
+                    _phantom: PhantomData,
+                });
+            }
+            let mut inner = inner.borrow_mut();
+            callbacks.append(&mut inner.disconnect_callbacks);
+            inner.disconnect_callbacks = callbacks;
+        }
+    }
+
+    /// Registers the callback invoked when a joystick was connected. The joysticks connected already are not notified, so use [`JoystickRegistry::joysticks`] for them.
+    pub fn on_connect(&self, callback: ConnectCallback<'video, Joystick>) {
+        self.inner.borrow_mut().connect_callbacks.push(callback);
+    }
+
+    /// Registers the callback invoked when a joystick was disconnected.
+    pub fn on_disconnect(&self, callback: DisconnectCallback<'video>) {
+        self.inner.borrow_mut().disconnect_callbacks.push(callback);
+    }
+
+    /// Returns the joystick of the id if it is connected. It is borrowed from the registry, so drop it before handling the next events.
+    #[must_use]
+    pub fn get(&self, id: JoystickId) -> Option<Ref<'_, Joystick>> {
+        let inner = self.inner.borrow();
+        inner
+            .joysticks
+            .contains_key(&id.id)
+            .then(|| Ref::map(inner, |inner| &inner.joysticks[&id.id]))
+    }
+
+    /// Returns the connected joysticks, in the order of connection. They are borrowed from the registry, so drop them before handling the next events.
+    #[must_use]
+    pub fn joysticks(&self) -> Vec<Ref<'_, Joystick>> {
+        let inner = self.inner.borrow();
+        inner
+            .joysticks
+            .keys()
+            .map(|id| Ref::map(Ref::clone(&inner), |inner| &inner.joysticks[id]))
+            .collect()
+    }
+
+    /// Returns the numbers of the connected joysticks.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.inner.borrow().joysticks.len()
+    }
+
+    /// Returns whether no joysticks are connected.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.inner.borrow().joysticks.is_empty()
+    }
+}
+
+impl Drop for JoystickRegistry<'_> {
+    fn drop(&mut self) {
+        for joystick in self.inner.borrow().joysticks.values() {
+            unsafe { bind::SDL_JoystickClose(joystick.ptr.as_ptr()) }
+        }
+        unsafe { bind::SDL_QuitSubSystem(bind::SDL_INIT_JOYSTICK) }
+    }
+}
@@ -0,0 +1,162 @@
+//! Virtual joysticks to emulate inputs without physical devices.
+
+use static_assertions::assert_not_impl_all;
+use std::ptr::NonNull;
+use typed_builder::TypedBuilder;
+
+use crate::{bind, Result, Sdl, SdlError};
+
+use super::{hat::PovHat, kind::JoystickKind, Joystick, JoystickId};
+
+/// A builder for the [`VirtualJoystick`].
+#[derive(Debug, TypedBuilder)]
+pub struct VirtualJoystickBuilder {
+    #[builder(default = JoystickKind::GameController)]
+    kind: JoystickKind,
+    #[builder(default)]
+    axes: u32,
+    #[builder(default)]
+    buttons: u32,
+    #[builder(default)]
+    hats: u32,
+}
+
+impl VirtualJoystickBuilder {
+    /// Attaches the virtual joystick and opens it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if failed to attach or open the virtual joystick.
+    pub fn build(self) -> Result<VirtualJoystick> {
+        unsafe {
+            bind::SDL_InitSubSystem(bind::SDL_INIT_JOYSTICK);
+        }
+        let device_index = unsafe {
+            bind::SDL_JoystickAttachVirtual(
+                self.kind.as_raw(),
+                self.axes as _,
+                self.buttons as _,
+                self.hats as _,
+            )
+        };
+        if device_index < 0 {
+            let msg = Sdl::error();
+            unsafe { bind::SDL_QuitSubSystem(bind::SDL_INIT_JOYSTICK) }
+            return Err(SdlError::Others { msg });
+        }
+        let ptr = unsafe { bind::SDL_JoystickOpen(device_index) };
+        match NonNull::new(ptr) {
+            Some(ptr) => Ok(VirtualJoystick {
+                joystick: Joystick { ptr },
+            }),
+            None => {
+                let msg = Sdl::error();
+                unsafe {
+                    bind::SDL_JoystickDetachVirtual(device_index);
+                    bind::SDL_QuitSubSystem(bind::SDL_INIT_JOYSTICK);
+                }
+                Err(SdlError::Others { msg })
+            }
+        }
+    }
+}
+
+/// A virtual joystick attached to the system. It appears as same as a physical joystick, in [`super::JoystickSet`] and [`crate::event::game_controller::GameControllerSet`] if it is recognized as a game controller.
+///
+/// The values set to this are reflected on updating the joysticks, such as polling the events. Dropping this will detach the virtual joystick.
+pub struct VirtualJoystick {
+    joystick: Joystick,
+}
+
+impl std::fmt::Debug for VirtualJoystick {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VirtualJoystick")
+            .field("joystick", &self.joystick)
+            .finish()
+    }
+}
+
+assert_not_impl_all!(VirtualJoystick: Send, Sync);
+
+impl VirtualJoystick {
+    /// Returns the joystick of this.
+    #[must_use]
+    pub fn joystick(&self) -> &Joystick {
+        &self.joystick
+    }
+
+    /// Returns the instance id [`JoystickId`].
+    #[must_use]
+    pub fn instance_id(&self) -> JoystickId {
+        self.joystick.instance_id()
+    }
+
+    /// Sets the value of the axis.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the axis of `index` does not exist.
+    pub fn set_axis(&self, index: u32, value: i16) -> Result<()> {
+        let ret = unsafe {
+            bind::SDL_JoystickSetVirtualAxis(self.joystick.ptr.as_ptr(), index as _, value)
+        };
+        if ret != 0 {
+            return Err(SdlError::Others { msg: Sdl::error() });
+        }
+        Ok(())
+    }
+
+    /// Sets whether the button is pressed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the button of `index` does not exist.
+    pub fn set_button(&self, index: u32, is_pressed: bool) -> Result<()> {
+        let ret = unsafe {
+            bind::SDL_JoystickSetVirtualButton(
+                self.joystick.ptr.as_ptr(),
+                index as _,
+                if is_pressed {
+                    bind::SDL_PRESSED
+                } else {
+                    bind::SDL_RELEASED
+                } as u8,
+            )
+        };
+        if ret != 0 {
+            return Err(SdlError::Others { msg: Sdl::error() });
+        }
+        Ok(())
+    }
+
+    /// Sets the direction of the hat.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the hat of `index` does not exist.
+    pub fn set_hat(&self, index: u32, value: PovHat) -> Result<()> {
+        let ret = unsafe {
+            bind::SDL_JoystickSetVirtualHat(self.joystick.ptr.as_ptr(), index as _, value.bits())
+        };
+        if ret != 0 {
+            return Err(SdlError::Others { msg: Sdl::error() });
+        }
+        Ok(())
+    }
+}
+
+impl Drop for VirtualJoystick {
+    fn drop(&mut self) {
+        let id = unsafe { bind::SDL_JoystickInstanceID(self.joystick.ptr.as_ptr()) };
+        unsafe { bind::SDL_JoystickClose(self.joystick.ptr.as_ptr()) }
+        let num_joysticks = unsafe { bind::SDL_NumJoysticks() };
+        if let Some(device_index) = (0..num_joysticks)
+            .find(|&index| unsafe { bind::SDL_JoystickGetDeviceInstanceID(index) } == id)
+        {
+            unsafe {
+                bind::SDL_JoystickDetachVirtual(device_index);
+            }
+        }
+        unsafe { bind::SDL_QuitSubSystem(bind::SDL_INIT_JOYSTICK) }
+    }
+}
@@ -9,6 +9,27 @@ use crate::bind;
 pub mod key_code;
 pub mod key_mod;
 pub mod scan_code;
+pub mod state;
+
+/// An error to tell the name is not corresponded to any key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownKeyNameError {
+    name: String,
+}
+
+impl UnknownKeyNameError {
+    pub(crate) fn new(name: &str) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+impl std::fmt::Display for UnknownKeyNameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown key name: {}", self.name)
+    }
+}
+
+impl std::error::Error for UnknownKeyNameError {}
 
 /// A symbol on the keyboard with the modification and the relationship of actual and virtual key code.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -26,7 +47,7 @@ impl From<bind::SDL_Keysym> for KeySymbol {
         Self {
             scan_code: sym.scancode.into(),
             key_code: sym.sym.into(),
-            key_mod: KeyMod::from_bits(sym.mod_).unwrap(),
+            key_mod: KeyMod::from_bits_truncate(sym.mod_),
         }
     }
 }
@@ -57,3 +78,18 @@ impl From<bind::SDL_KeyboardEvent> for KeyboardEvent {
         }
     }
 }
+
+/// An event on the keymap was changed, such as the input language or the keyboard layout was switched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeymapChangedEvent {
+    /// When this event occurred.
+    pub timestamp: u32,
+}
+
+impl From<bind::SDL_CommonEvent> for KeymapChangedEvent {
+    fn from(raw: bind::SDL_CommonEvent) -> Self {
+        Self {
+            timestamp: raw.timestamp,
+        }
+    }
+}
@@ -6,9 +6,9 @@ use std::ffi::{CStr, CString};
 
 use crate::{bind, EnumInt};
 
-use super::scan_code::ScanCode;
+use super::{scan_code::ScanCode, UnknownKeyNameError};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum KeyCode {
     Unknown,
     Return,
@@ -514,6 +514,26 @@ impl KeyCode {
     }
 }
 
+impl std::fmt::Display for KeyCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name().unwrap_or_default())
+    }
+}
+
+impl std::str::FromStr for KeyCode {
+    type Err = UnknownKeyNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let c_str = CString::new(s).map_err(|_| UnknownKeyNameError::new(s))?;
+        let key_code: Self = unsafe { bind::SDL_GetKeyFromName(c_str.as_ptr()) }.into();
+        if key_code == KeyCode::Unknown {
+            Err(UnknownKeyNameError::new(s))
+        } else {
+            Ok(key_code)
+        }
+    }
+}
+
 impl From<ScanCode> for KeyCode {
     fn from(scan_code: ScanCode) -> Self {
         let raw = scan_code.as_raw();
@@ -2,9 +2,11 @@
 
 #![allow(missing_docs)]
 
+use std::ffi::{CStr, CString};
+
 use crate::bind;
 
-use super::key_code::KeyCode;
+use super::{key_code::KeyCode, UnknownKeyNameError};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ScanCode {
@@ -256,6 +258,19 @@ pub enum ScanCode {
 pub const NUM_SCANCODES: u16 = 512;
 
 impl ScanCode {
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        name.parse().ok()
+    }
+
+    #[must_use]
+    pub fn name(&self) -> Option<String> {
+        let name: String = unsafe { CStr::from_ptr(bind::SDL_GetScancodeName(self.as_raw())) }
+            .to_string_lossy()
+            .into();
+        (!name.is_empty()).then(|| name)
+    }
+
     pub(crate) fn as_raw(self) -> bind::SDL_Scancode {
         match self {
             ScanCode::Unknown => bind::SDL_SCANCODE_UNKNOWN,
@@ -505,6 +520,26 @@ impl ScanCode {
     }
 }
 
+impl std::fmt::Display for ScanCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name().unwrap_or_default())
+    }
+}
+
+impl std::str::FromStr for ScanCode {
+    type Err = UnknownKeyNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let c_str = CString::new(s).map_err(|_| UnknownKeyNameError::new(s))?;
+        let scan_code: Self = unsafe { bind::SDL_GetScancodeFromName(c_str.as_ptr()) }.into();
+        if scan_code == ScanCode::Unknown {
+            Err(UnknownKeyNameError::new(s))
+        } else {
+            Ok(scan_code)
+        }
+    }
+}
+
 impl From<KeyCode> for ScanCode {
     fn from(key_code: KeyCode) -> Self {
         unsafe { bind::SDL_GetScancodeFromKey(key_code.as_raw()) }.into()
@@ -0,0 +1,54 @@
+//! A snapshot of the keyboard state.
+
+use std::os::raw::c_int;
+
+use crate::bind;
+
+use super::scan_code::ScanCode;
+
+/// A snapshot of the pressed state of all the keys on the keyboard. The state is updated by polling the events.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyboardState {
+    states: Vec<bool>,
+}
+
+impl KeyboardState {
+    /// Takes the snapshot of the current keyboard state.
+    #[must_use]
+    pub fn current() -> Self {
+        let mut len: c_int = 0;
+        let ptr = unsafe { bind::SDL_GetKeyboardState(&mut len) };
+        let raw = unsafe { std::slice::from_raw_parts(ptr, len as usize) };
+        Self {
+            states: raw.iter().map(|&state| state != 0).collect(),
+        }
+    }
+
+    /// Returns whether the key of the scan code is pressed.
+    #[must_use]
+    pub fn is_pressed(&self, scan_code: ScanCode) -> bool {
+        self.states
+            .get(scan_code.as_raw() as usize)
+            .copied()
+            .unwrap_or(false)
+    }
+
+    /// Returns the iterator of the scan codes of the pressed keys.
+    pub fn pressed(&self) -> impl Iterator<Item = ScanCode> + '_ {
+        self.states
+            .iter()
+            .enumerate()
+            .filter(|(_, &state)| state)
+            .map(|(index, _)| (index as bind::SDL_Scancode).into())
+    }
+}
+
+impl std::ops::Index<ScanCode> for KeyboardState {
+    type Output = bool;
+
+    fn index(&self, scan_code: ScanCode) -> &Self::Output {
+        self.states
+            .get(scan_code.as_raw() as usize)
+            .unwrap_or(&false)
+    }
+}
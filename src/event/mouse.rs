@@ -1,13 +1,33 @@
 //! Events and utilities for the mouse.
 
 use crate::geo::Point;
-use crate::{bind, EnumInt};
+use crate::{bind, window::Window, EnumInt, Result, Sdl, SdlError, Video};
 
+pub mod capture;
 pub mod cursor;
 pub mod relative;
+pub mod state;
+
+/// Moves the cursor to the position in the window.
+pub fn warp_in_window(window: &Window, pos: Point) {
+    unsafe { bind::SDL_WarpMouseInWindow(window.as_ptr(), pos.x, pos.y) }
+}
+
+/// Moves the cursor to the position on the desktop.
+///
+/// # Errors
+///
+/// Returns `Err` if it is not supported on the platform.
+pub fn warp_global(_: &Video, pos: Point) -> Result<()> {
+    let ret = unsafe { bind::SDL_WarpMouseGlobal(pos.x, pos.y) };
+    if ret != 0 {
+        return Err(SdlError::Others { msg: Sdl::error() });
+    }
+    Ok(())
+}
 
 /// A kind of the mouse button.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum MouseButton {
     /// The left button of the mouse.
     Left,
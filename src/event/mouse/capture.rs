@@ -0,0 +1,47 @@
+//! Capture the mouse to track it outside of the window.
+
+use static_assertions::assert_not_impl_all;
+use std::marker::PhantomData;
+
+use crate::{bind, Sdl, SdlError, Video};
+
+/// It captures the mouse, so that the mouse events are delivered even if the cursor is outside of the window.
+/// Dropping this will release the capture.
+pub struct MouseCapture<'video> {
+    video: PhantomData<&'video Video<'video>>,
+}
+
+impl std::fmt::Debug for MouseCapture<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MouseCapture").finish()
+    }
+}
+
+assert_not_impl_all!(MouseCapture: Send, Sync);
+
+impl<'video> MouseCapture<'video> {
+    /// Starts capturing the mouse.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(UnsupportedFeature)` if this is not supported, or `Err(Others)` if failed to capture such as no window has focus.
+    pub fn new(_: &'video Video) -> Result<Self, SdlError> {
+        let ret = unsafe { bind::SDL_CaptureMouse(bind::SDL_TRUE) };
+        if ret != 0 {
+            let error = Sdl::error();
+            if error == "That operation is not supported" {
+                return Err(SdlError::UnsupportedFeature);
+            }
+            return Err(SdlError::Others { msg: error });
+        }
+        Ok(Self { video: PhantomData })
+    }
+}
+
+impl Drop for MouseCapture<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = bind::SDL_CaptureMouse(bind::SDL_FALSE);
+        }
+    }
+}
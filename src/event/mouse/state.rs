@@ -0,0 +1,83 @@
+//! Querying the current state of the mouse.
+
+use bitflags::bitflags;
+use std::os::raw::c_int;
+
+use crate::geo::Point;
+use crate::{bind, Video};
+
+use super::MouseButton;
+
+bitflags! {
+    /// A set of the pressed mouse buttons.
+    pub struct MouseButtons : u32 {
+        /// The left button is pressed.
+        const LEFT = 1 << 0;
+        /// The middle or wheel button is pressed.
+        const MIDDLE = 1 << 1;
+        /// The right button is pressed.
+        const RIGHT = 1 << 2;
+        /// The x1 button is pressed.
+        const X1 = 1 << 3;
+        /// The x2 button is pressed.
+        const X2 = 1 << 4;
+    }
+}
+
+impl MouseButtons {
+    /// Returns whether the button is pressed.
+    #[must_use]
+    pub fn is_pressed(&self, button: MouseButton) -> bool {
+        self.contains(button.into())
+    }
+}
+
+impl From<MouseButton> for MouseButtons {
+    fn from(button: MouseButton) -> Self {
+        match button {
+            MouseButton::Left => MouseButtons::LEFT,
+            MouseButton::Middle => MouseButtons::MIDDLE,
+            MouseButton::Right => MouseButtons::RIGHT,
+            MouseButton::X1 => MouseButtons::X1,
+            MouseButton::X2 => MouseButtons::X2,
+        }
+    }
+}
+
+/// A state of the mouse, the position and the pressed buttons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MouseState {
+    /// The position of the cursor, or the amount of the motion for [`MouseState::relative`].
+    pub pos: Point,
+    /// The pressed buttons of the mouse.
+    pub buttons: MouseButtons,
+}
+
+impl MouseState {
+    fn query(f: unsafe extern "C" fn(*mut c_int, *mut c_int) -> u32) -> Self {
+        let (mut x, mut y) = (0, 0);
+        let bits = unsafe { f(&mut x, &mut y) };
+        Self {
+            pos: Point { x, y },
+            buttons: MouseButtons::from_bits_truncate(bits),
+        }
+    }
+
+    /// Returns the current state of the mouse, with the position relative to the focused window.
+    #[must_use]
+    pub fn current(_: &Video) -> Self {
+        Self::query(bind::SDL_GetMouseState)
+    }
+
+    /// Returns the current state of the mouse, with the position relative to the desktop.
+    #[must_use]
+    pub fn global(_: &Video) -> Self {
+        Self::query(bind::SDL_GetGlobalMouseState)
+    }
+
+    /// Returns the current state of the mouse, with the amount of the motion since the last call of this.
+    #[must_use]
+    pub fn relative(_: &Video) -> Self {
+        Self::query(bind::SDL_GetRelativeMouseState)
+    }
+}
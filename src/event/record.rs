@@ -0,0 +1,1034 @@
+//! Recording the events and replaying them deterministically.
+//!
+//! An [`EventRecorder`] attached to [`EventBox`] captures the events decoded by it into an [`EventLog`]. The log can be saved as text by [`std::fmt::Display`], and loaded by [`std::str::FromStr`]. An [`EventPlayer`] pushes the recorded events back into the queue.
+//!
+//! The log stores the decoded fields instead of the memory of the raw events, so it does not depend on the version of SDL2 or the target. Only the quit, window, keyboard, text input, mouse, joystick, game controller, touch finger, clipboard and keymap events are recorded. The device added events are not recorded, because they cannot be replayed on another environment.
+
+use std::str::{FromStr, SplitWhitespace};
+
+use crate::{bind, EnumInt, Result, Sdl, SdlError};
+
+use super::EventBox;
+
+const TEXT_INPUT_CAPACITY: usize = 32;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Payload {
+    Quit,
+    Window {
+        window_id: u32,
+        event: u8,
+        data1: i32,
+        data2: i32,
+    },
+    Key {
+        is_pressed: bool,
+        window_id: u32,
+        repeat: u8,
+        scan_code: u32,
+        key_code: i32,
+        key_mod: u16,
+    },
+    TextInput {
+        window_id: u32,
+        text: String,
+    },
+    MouseMotion {
+        window_id: u32,
+        which: u32,
+        state: u32,
+        x: i32,
+        y: i32,
+        x_rel: i32,
+        y_rel: i32,
+    },
+    MouseButton {
+        is_pressed: bool,
+        window_id: u32,
+        which: u32,
+        button: u8,
+        clicks: u8,
+        x: i32,
+        y: i32,
+    },
+    MouseWheel {
+        window_id: u32,
+        which: u32,
+        x: i32,
+        y: i32,
+        direction: u32,
+        precise_x: f32,
+        precise_y: f32,
+    },
+    JoyAxis {
+        which: i32,
+        axis: u8,
+        value: i16,
+    },
+    JoyBall {
+        which: i32,
+        ball: u8,
+        x_rel: i16,
+        y_rel: i16,
+    },
+    JoyHat {
+        which: i32,
+        hat: u8,
+        value: u8,
+    },
+    JoyButton {
+        is_pressed: bool,
+        which: i32,
+        button: u8,
+    },
+    JoyRemoved {
+        which: i32,
+    },
+    ControllerAxis {
+        which: i32,
+        axis: u8,
+        value: i16,
+    },
+    ControllerButton {
+        is_pressed: bool,
+        which: i32,
+        button: u8,
+    },
+    ControllerRemoved {
+        which: i32,
+    },
+    ControllerRemapped {
+        which: i32,
+    },
+    Finger {
+        ty: EnumInt,
+        touch_id: i64,
+        finger_id: i64,
+        x: f32,
+        y: f32,
+        dx: f32,
+        dy: f32,
+        pressure: f32,
+        window_id: u32,
+    },
+    ClipboardUpdate,
+    KeymapChanged,
+}
+
+impl Payload {
+    fn from_raw(raw: &bind::SDL_Event) -> Option<Self> {
+        let ty = unsafe { raw.type_ } as EnumInt;
+        let payload = unsafe {
+            match ty {
+                bind::SDL_QUIT => Self::Quit,
+                bind::SDL_WINDOWEVENT => Self::Window {
+                    window_id: raw.window.windowID,
+                    event: raw.window.event,
+                    data1: raw.window.data1,
+                    data2: raw.window.data2,
+                },
+                bind::SDL_KEYDOWN | bind::SDL_KEYUP => Self::Key {
+                    is_pressed: ty == bind::SDL_KEYDOWN,
+                    window_id: raw.key.windowID,
+                    repeat: raw.key.repeat,
+                    scan_code: raw.key.keysym.scancode as u32,
+                    key_code: raw.key.keysym.sym as i32,
+                    key_mod: raw.key.keysym.mod_,
+                },
+                bind::SDL_TEXTINPUT => Self::TextInput {
+                    window_id: raw.text.windowID,
+                    text: std::ffi::CStr::from_ptr(raw.text.text.as_ptr())
+                        .to_string_lossy()
+                        .into(),
+                },
+                bind::SDL_MOUSEMOTION => Self::MouseMotion {
+                    window_id: raw.motion.windowID,
+                    which: raw.motion.which,
+                    state: raw.motion.state,
+                    x: raw.motion.x,
+                    y: raw.motion.y,
+                    x_rel: raw.motion.xrel,
+                    y_rel: raw.motion.yrel,
+                },
+                bind::SDL_MOUSEBUTTONDOWN | bind::SDL_MOUSEBUTTONUP => Self::MouseButton {
+                    is_pressed: ty == bind::SDL_MOUSEBUTTONDOWN,
+                    window_id: raw.button.windowID,
+                    which: raw.button.which,
+                    button: raw.button.button,
+                    clicks: raw.button.clicks,
+                    x: raw.button.x,
+                    y: raw.button.y,
+                },
+                bind::SDL_MOUSEWHEEL => Self::MouseWheel {
+                    window_id: raw.wheel.windowID,
+                    which: raw.wheel.which,
+                    x: raw.wheel.x,
+                    y: raw.wheel.y,
+                    direction: raw.wheel.direction,
+                    precise_x: raw.wheel.preciseX,
+                    precise_y: raw.wheel.preciseY,
+                },
+                bind::SDL_JOYAXISMOTION => Self::JoyAxis {
+                    which: raw.jaxis.which,
+                    axis: raw.jaxis.axis,
+                    value: raw.jaxis.value,
+                },
+                bind::SDL_JOYBALLMOTION => Self::JoyBall {
+                    which: raw.jball.which,
+                    ball: raw.jball.ball,
+                    x_rel: raw.jball.xrel,
+                    y_rel: raw.jball.yrel,
+                },
+                bind::SDL_JOYHATMOTION => Self::JoyHat {
+                    which: raw.jhat.which,
+                    hat: raw.jhat.hat,
+                    value: raw.jhat.value,
+                },
+                bind::SDL_JOYBUTTONDOWN | bind::SDL_JOYBUTTONUP => Self::JoyButton {
+                    is_pressed: ty == bind::SDL_JOYBUTTONDOWN,
+                    which: raw.jbutton.which,
+                    button: raw.jbutton.button,
+                },
+                bind::SDL_JOYDEVICEREMOVED => Self::JoyRemoved {
+                    which: raw.jdevice.which,
+                },
+                bind::SDL_CONTROLLERAXISMOTION => Self::ControllerAxis {
+                    which: raw.caxis.which,
+                    axis: raw.caxis.axis,
+                    value: raw.caxis.value,
+                },
+                bind::SDL_CONTROLLERBUTTONDOWN | bind::SDL_CONTROLLERBUTTONUP => {
+                    Self::ControllerButton {
+                        is_pressed: ty == bind::SDL_CONTROLLERBUTTONDOWN,
+                        which: raw.cbutton.which,
+                        button: raw.cbutton.button,
+                    }
+                }
+                bind::SDL_CONTROLLERDEVICEREMOVED => Self::ControllerRemoved {
+                    which: raw.cdevice.which,
+                },
+                bind::SDL_CONTROLLERDEVICEREMAPPED => Self::ControllerRemapped {
+                    which: raw.cdevice.which,
+                },
+                bind::SDL_FINGERDOWN | bind::SDL_FINGERMOTION | bind::SDL_FINGERUP => {
+                    Self::Finger {
+                        ty,
+                        touch_id: raw.tfinger.touchId,
+                        finger_id: raw.tfinger.fingerId,
+                        x: raw.tfinger.x,
+                        y: raw.tfinger.y,
+                        dx: raw.tfinger.dx,
+                        dy: raw.tfinger.dy,
+                        pressure: raw.tfinger.pressure,
+                        window_id: raw.tfinger.windowID,
+                    }
+                }
+                bind::SDL_CLIPBOARDUPDATE => Self::ClipboardUpdate,
+                bind::SDL_KEYMAPCHANGED => Self::KeymapChanged,
+                _ => return None,
+            }
+        };
+        Some(payload)
+    }
+
+    fn ty(&self) -> EnumInt {
+        match *self {
+            Self::Quit => bind::SDL_QUIT,
+            Self::Window { .. } => bind::SDL_WINDOWEVENT,
+            Self::Key { is_pressed, .. } => {
+                if is_pressed {
+                    bind::SDL_KEYDOWN
+                } else {
+                    bind::SDL_KEYUP
+                }
+            }
+            Self::TextInput { .. } => bind::SDL_TEXTINPUT,
+            Self::MouseMotion { .. } => bind::SDL_MOUSEMOTION,
+            Self::MouseButton { is_pressed, .. } => {
+                if is_pressed {
+                    bind::SDL_MOUSEBUTTONDOWN
+                } else {
+                    bind::SDL_MOUSEBUTTONUP
+                }
+            }
+            Self::MouseWheel { .. } => bind::SDL_MOUSEWHEEL,
+            Self::JoyAxis { .. } => bind::SDL_JOYAXISMOTION,
+            Self::JoyBall { .. } => bind::SDL_JOYBALLMOTION,
+            Self::JoyHat { .. } => bind::SDL_JOYHATMOTION,
+            Self::JoyButton { is_pressed, .. } => {
+                if is_pressed {
+                    bind::SDL_JOYBUTTONDOWN
+                } else {
+                    bind::SDL_JOYBUTTONUP
+                }
+            }
+            Self::JoyRemoved { .. } => bind::SDL_JOYDEVICEREMOVED,
+            Self::ControllerAxis { .. } => bind::SDL_CONTROLLERAXISMOTION,
+            Self::ControllerButton { is_pressed, .. } => {
+                if is_pressed {
+                    bind::SDL_CONTROLLERBUTTONDOWN
+                } else {
+                    bind::SDL_CONTROLLERBUTTONUP
+                }
+            }
+            Self::ControllerRemoved { .. } => bind::SDL_CONTROLLERDEVICEREMOVED,
+            Self::ControllerRemapped { .. } => bind::SDL_CONTROLLERDEVICEREMAPPED,
+            Self::Finger { ty, .. } => ty,
+            Self::ClipboardUpdate => bind::SDL_CLIPBOARDUPDATE,
+            Self::KeymapChanged => bind::SDL_KEYMAPCHANGED,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Quit => "quit",
+            Self::Window { .. } => "window",
+            Self::Key {
+                is_pressed: true, ..
+            } => "key_down",
+            Self::Key { .. } => "key_up",
+            Self::TextInput { .. } => "text_input",
+            Self::MouseMotion { .. } => "mouse_motion",
+            Self::MouseButton {
+                is_pressed: true, ..
+            } => "mouse_button_down",
+            Self::MouseButton { .. } => "mouse_button_up",
+            Self::MouseWheel { .. } => "mouse_wheel",
+            Self::JoyAxis { .. } => "joy_axis",
+            Self::JoyBall { .. } => "joy_ball",
+            Self::JoyHat { .. } => "joy_hat",
+            Self::JoyButton {
+                is_pressed: true, ..
+            } => "joy_button_down",
+            Self::JoyButton { .. } => "joy_button_up",
+            Self::JoyRemoved { .. } => "joy_removed",
+            Self::ControllerAxis { .. } => "controller_axis",
+            Self::ControllerButton {
+                is_pressed: true, ..
+            } => "controller_button_down",
+            Self::ControllerButton { .. } => "controller_button_up",
+            Self::ControllerRemoved { .. } => "controller_removed",
+            Self::ControllerRemapped { .. } => "controller_remapped",
+            Self::Finger {
+                ty: bind::SDL_FINGERDOWN,
+                ..
+            } => "finger_down",
+            Self::Finger {
+                ty: bind::SDL_FINGERMOTION,
+                ..
+            } => "finger_motion",
+            Self::Finger { .. } => "finger_up",
+            Self::ClipboardUpdate => "clipboard_update",
+            Self::KeymapChanged => "keymap_changed",
+        }
+    }
+
+    fn to_raw(&self, timestamp: u32) -> bind::SDL_Event {
+        let mut raw: bind::SDL_Event = unsafe { std::mem::zeroed() };
+        unsafe {
+            raw.common.type_ = self.ty() as u32;
+            raw.common.timestamp = timestamp;
+            match *self {
+                Self::Quit | Self::ClipboardUpdate | Self::KeymapChanged => {}
+                Self::Window {
+                    window_id,
+                    event,
+                    data1,
+                    data2,
+                } => {
+                    raw.window.windowID = window_id;
+                    raw.window.event = event;
+                    raw.window.data1 = data1;
+                    raw.window.data2 = data2;
+                }
+                Self::Key {
+                    is_pressed,
+                    window_id,
+                    repeat,
+                    scan_code,
+                    key_code,
+                    key_mod,
+                } => {
+                    raw.key.windowID = window_id;
+                    raw.key.state = if is_pressed {
+                        bind::SDL_PRESSED
+                    } else {
+                        bind::SDL_RELEASED
+                    } as u8;
+                    raw.key.repeat = repeat;
+                    raw.key.keysym.scancode = scan_code as _;
+                    raw.key.keysym.sym = key_code as _;
+                    raw.key.keysym.mod_ = key_mod;
+                }
+                Self::TextInput {
+                    window_id,
+                    ref text,
+                } => {
+                    raw.text.windowID = window_id;
+                    let len = text.len().min(TEXT_INPUT_CAPACITY - 1);
+                    for (dst, &src) in raw.text.text.iter_mut().zip(&text.as_bytes()[..len]) {
+                        *dst = src as _;
+                    }
+                }
+                Self::MouseMotion {
+                    window_id,
+                    which,
+                    state,
+                    x,
+                    y,
+                    x_rel,
+                    y_rel,
+                } => {
+                    raw.motion.windowID = window_id;
+                    raw.motion.which = which;
+                    raw.motion.state = state;
+                    raw.motion.x = x;
+                    raw.motion.y = y;
+                    raw.motion.xrel = x_rel;
+                    raw.motion.yrel = y_rel;
+                }
+                Self::MouseButton {
+                    is_pressed,
+                    window_id,
+                    which,
+                    button,
+                    clicks,
+                    x,
+                    y,
+                } => {
+                    raw.button.windowID = window_id;
+                    raw.button.which = which;
+                    raw.button.button = button;
+                    raw.button.state = if is_pressed {
+                        bind::SDL_PRESSED
+                    } else {
+                        bind::SDL_RELEASED
+                    } as u8;
+                    raw.button.clicks = clicks;
+                    raw.button.x = x;
+                    raw.button.y = y;
+                }
+                Self::MouseWheel {
+                    window_id,
+                    which,
+                    x,
+                    y,
+                    direction,
+                    precise_x,
+                    precise_y,
+                } => {
+                    raw.wheel.windowID = window_id;
+                    raw.wheel.which = which;
+                    raw.wheel.x = x;
+                    raw.wheel.y = y;
+                    raw.wheel.direction = direction;
+                    raw.wheel.preciseX = precise_x;
+                    raw.wheel.preciseY = precise_y;
+                }
+                Self::JoyAxis { which, axis, value } => {
+                    raw.jaxis.which = which;
+                    raw.jaxis.axis = axis;
+                    raw.jaxis.value = value;
+                }
+                Self::JoyBall {
+                    which,
+                    ball,
+                    x_rel,
+                    y_rel,
+                } => {
+                    raw.jball.which = which;
+                    raw.jball.ball = ball;
+                    raw.jball.xrel = x_rel;
+                    raw.jball.yrel = y_rel;
+                }
+                Self::JoyHat { which, hat, value } => {
+                    raw.jhat.which = which;
+                    raw.jhat.hat = hat;
+                    raw.jhat.value = value;
+                }
+                Self::JoyButton {
+                    is_pressed,
+                    which,
+                    button,
+                } => {
+                    raw.jbutton.which = which;
+                    raw.jbutton.button = button;
+                    raw.jbutton.state = if is_pressed {
+                        bind::SDL_PRESSED
+                    } else {
+                        bind::SDL_RELEASED
+                    } as u8;
+                }
+                Self::JoyRemoved { which } => raw.jdevice.which = which,
+                Self::ControllerAxis { which, axis, value } => {
+                    raw.caxis.which = which;
+                    raw.caxis.axis = axis;
+                    raw.caxis.value = value;
+                }
+                Self::ControllerButton {
+                    is_pressed,
+                    which,
+                    button,
+                } => {
+                    raw.cbutton.which = which;
+                    raw.cbutton.button = button;
+                    raw.cbutton.state = if is_pressed {
+                        bind::SDL_PRESSED
+                    } else {
+                        bind::SDL_RELEASED
+                    } as u8;
+                }
+                Self::ControllerRemoved { which } | Self::ControllerRemapped { which } => {
+                    raw.cdevice.which = which;
+                }
+                Self::Finger {
+                    touch_id,
+                    finger_id,
+                    x,
+                    y,
+                    dx,
+                    dy,
+                    pressure,
+                    window_id,
+                    ..
+                } => {
+                    raw.tfinger.touchId = touch_id;
+                    raw.tfinger.fingerId = finger_id;
+                    raw.tfinger.x = x;
+                    raw.tfinger.y = y;
+                    raw.tfinger.dx = dx;
+                    raw.tfinger.dy = dy;
+                    raw.tfinger.pressure = pressure;
+                    raw.tfinger.windowID = window_id;
+                }
+            }
+        }
+        raw
+    }
+
+    fn write_fields(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Quit | Self::ClipboardUpdate | Self::KeymapChanged => Ok(()),
+            Self::Window {
+                window_id,
+                event,
+                data1,
+                data2,
+            } => write!(f, " {} {} {} {}", window_id, event, data1, data2),
+            Self::Key {
+                window_id,
+                repeat,
+                scan_code,
+                key_code,
+                key_mod,
+                ..
+            } => write!(
+                f,
+                " {} {} {} {} {}",
+                window_id, repeat, scan_code, key_code, key_mod
+            ),
+            Self::TextInput { window_id, text } => {
+                write!(f, " {} ", window_id)?;
+                for byte in text.as_bytes() {
+                    write!(f, "{:02x}", byte)?;
+                }
+                Ok(())
+            }
+            Self::MouseMotion {
+                window_id,
+                which,
+                state,
+                x,
+                y,
+                x_rel,
+                y_rel,
+            } => write!(
+                f,
+                " {} {} {} {} {} {} {}",
+                window_id, which, state, x, y, x_rel, y_rel
+            ),
+            Self::MouseButton {
+                window_id,
+                which,
+                button,
+                clicks,
+                x,
+                y,
+                ..
+            } => write!(
+                f,
+                " {} {} {} {} {} {}",
+                window_id, which, button, clicks, x, y
+            ),
+            Self::MouseWheel {
+                window_id,
+                which,
+                x,
+                y,
+                direction,
+                precise_x,
+                precise_y,
+            } => write!(
+                f,
+                " {} {} {} {} {} {} {}",
+                window_id, which, x, y, direction, precise_x, precise_y
+            ),
+            Self::JoyAxis { which, axis, value } | Self::ControllerAxis { which, axis, value } => {
+                write!(f, " {} {} {}", which, axis, value)
+            }
+            Self::JoyBall {
+                which,
+                ball,
+                x_rel,
+                y_rel,
+            } => write!(f, " {} {} {} {}", which, ball, x_rel, y_rel),
+            Self::JoyHat { which, hat, value } => write!(f, " {} {} {}", which, hat, value),
+            Self::JoyButton { which, button, .. }
+            | Self::ControllerButton { which, button, .. } => {
+                write!(f, " {} {}", which, button)
+            }
+            Self::JoyRemoved { which }
+            | Self::ControllerRemoved { which }
+            | Self::ControllerRemapped { which } => write!(f, " {}", which),
+            Self::Finger {
+                touch_id,
+                finger_id,
+                x,
+                y,
+                dx,
+                dy,
+                pressure,
+                window_id,
+                ..
+            } => write!(
+                f,
+                " {} {} {} {} {} {} {} {}",
+                touch_id, finger_id, x, y, dx, dy, pressure, window_id
+            ),
+        }
+    }
+
+    fn parse(name: &str, fields: &mut SplitWhitespace) -> Option<Self> {
+        fn next<T: FromStr>(fields: &mut SplitWhitespace) -> Option<T> {
+            fields.next()?.parse().ok()
+        }
+        let payload = match name {
+            "quit" => Self::Quit,
+            "clipboard_update" => Self::ClipboardUpdate,
+            "keymap_changed" => Self::KeymapChanged,
+            "window" => Self::Window {
+                window_id: next(fields)?,
+                event: next(fields)?,
+                data1: next(fields)?,
+                data2: next(fields)?,
+            },
+            "key_down" | "key_up" => Self::Key {
+                is_pressed: name == "key_down",
+                window_id: next(fields)?,
+                repeat: next(fields)?,
+                scan_code: next(fields)?,
+                key_code: next(fields)?,
+                key_mod: next(fields)?,
+            },
+            "text_input" => {
+                let window_id = next(fields)?;
+                let hex = fields.next().unwrap_or_default();
+                let bytes = (0..hex.len())
+                    .step_by(2)
+                    .map(|i| {
+                        hex.get(i..i + 2)
+                            .and_then(|b| u8::from_str_radix(b, 16).ok())
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                if TEXT_INPUT_CAPACITY <= bytes.len() || bytes.contains(&0) {
+                    return None;
+                }
+                Self::TextInput {
+                    window_id,
+                    text: String::from_utf8(bytes).ok()?,
+                }
+            }
+            "mouse_motion" => Self::MouseMotion {
+                window_id: next(fields)?,
+                which: next(fields)?,
+                state: next(fields)?,
+                x: next(fields)?,
+                y: next(fields)?,
+                x_rel: next(fields)?,
+                y_rel: next(fields)?,
+            },
+            "mouse_button_down" | "mouse_button_up" => Self::MouseButton {
+                is_pressed: name == "mouse_button_down",
+                window_id: next(fields)?,
+                which: next(fields)?,
+                button: next(fields)?,
+                clicks: next(fields)?,
+                x: next(fields)?,
+                y: next(fields)?,
+            },
+            "mouse_wheel" => Self::MouseWheel {
+                window_id: next(fields)?,
+                which: next(fields)?,
+                x: next(fields)?,
+                y: next(fields)?,
+                direction: next(fields)?,
+                precise_x: next(fields)?,
+                precise_y: next(fields)?,
+            },
+            "joy_axis" => Self::JoyAxis {
+                which: next(fields)?,
+                axis: next(fields)?,
+                value: next(fields)?,
+            },
+            "joy_ball" => Self::JoyBall {
+                which: next(fields)?,
+                ball: next(fields)?,
+                x_rel: next(fields)?,
+                y_rel: next(fields)?,
+            },
+            "joy_hat" => Self::JoyHat {
+                which: next(fields)?,
+                hat: next(fields)?,
+                value: next(fields)?,
+            },
+            "joy_button_down" | "joy_button_up" => Self::JoyButton {
+                is_pressed: name == "joy_button_down",
+                which: next(fields)?,
+                button: next(fields)?,
+            },
+            "joy_removed" => Self::JoyRemoved {
+                which: next(fields)?,
+            },
+            "controller_axis" => Self::ControllerAxis {
+                which: next(fields)?,
+                axis: next(fields)?,
+                value: next(fields)?,
+            },
+            "controller_button_down" | "controller_button_up" => Self::ControllerButton {
+                is_pressed: name == "controller_button_down",
+                which: next(fields)?,
+                button: next(fields)?,
+            },
+            "controller_removed" => Self::ControllerRemoved {
+                which: next(fields)?,
+            },
+            "controller_remapped" => Self::ControllerRemapped {
+                which: next(fields)?,
+            },
+            "finger_down" | "finger_motion" | "finger_up" => Self::Finger {
+                ty: match name {
+                    "finger_down" => bind::SDL_FINGERDOWN,
+                    "finger_motion" => bind::SDL_FINGERMOTION,
+                    _ => bind::SDL_FINGERUP,
+                },
+                touch_id: next(fields)?,
+                finger_id: next(fields)?,
+                x: next(fields)?,
+                y: next(fields)?,
+                dx: next(fields)?,
+                dy: next(fields)?,
+                pressure: next(fields)?,
+                window_id: next(fields)?,
+            },
+            _ => return None,
+        };
+        Some(payload)
+    }
+}
+
+/// An event recorded by [`EventRecorder`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedEvent {
+    /// The frame number when this event was recorded, see [`EventRecorder::next_frame`].
+    pub frame: u32,
+    /// When this event occurred originally.
+    pub timestamp: u32,
+    payload: Payload,
+}
+
+impl RecordedEvent {
+    fn from_raw(frame: u32, raw: &bind::SDL_Event) -> Option<Self> {
+        Some(Self {
+            frame,
+            timestamp: unsafe { raw.common.timestamp },
+            payload: Payload::from_raw(raw)?,
+        })
+    }
+
+    fn to_raw(&self) -> bind::SDL_Event {
+        self.payload.to_raw(self.timestamp)
+    }
+}
+
+/// A recorder of the events, which can be attached to [`EventBox`] by [`EventBox::attach_recorder`].
+#[derive(Debug, Default, Clone)]
+pub struct EventRecorder {
+    frame: u32,
+    events: Vec<RecordedEvent>,
+}
+
+impl EventRecorder {
+    /// Constructs an empty recorder.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Advances the frame number of the recorded events. Call this once a frame to replay by [`EventPlayer::push_next_frame`].
+    pub fn next_frame(&mut self) {
+        self.frame += 1;
+    }
+
+    pub(super) fn record(&mut self, raw: &bind::SDL_Event) {
+        if let Some(event) = RecordedEvent::from_raw(self.frame, raw) {
+            self.events.push(event);
+        }
+    }
+
+    /// Returns the recorded events.
+    #[must_use]
+    pub fn events(&self) -> &[RecordedEvent] {
+        &self.events
+    }
+
+    /// Converts into the log of the recorded events.
+    #[must_use]
+    pub fn into_log(self) -> EventLog {
+        EventLog {
+            events: self.events,
+        }
+    }
+}
+
+/// A log of the recorded events. A line of the text representation is the frame number, the timestamp, the kind of the event and its fields, separated by spaces.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct EventLog {
+    events: Vec<RecordedEvent>,
+}
+
+impl EventLog {
+    /// Returns the recorded events.
+    #[must_use]
+    pub fn events(&self) -> &[RecordedEvent] {
+        &self.events
+    }
+}
+
+impl std::fmt::Display for EventLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for event in &self.events {
+            write!(
+                f,
+                "{} {} {}",
+                event.frame,
+                event.timestamp,
+                event.payload.name()
+            )?;
+            event.payload.write_fields(f)?;
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// An error to tell the failure on parsing [`EventLog`] from string, showing which line is invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseEventLogError {
+    line: usize,
+}
+
+impl std::fmt::Display for ParseEventLogError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid event log at line {}", self.line)
+    }
+}
+
+impl std::error::Error for ParseEventLogError {}
+
+impl std::str::FromStr for EventLog {
+    type Err = ParseEventLogError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        s.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                let err = ParseEventLogError { line: index + 1 };
+                let mut fields = line.split_whitespace();
+                let frame = fields.next().and_then(|s| s.parse().ok()).ok_or(err)?;
+                let timestamp = fields.next().and_then(|s| s.parse().ok()).ok_or(err)?;
+                let name = fields.next().ok_or(err)?;
+                let payload = Payload::parse(name, &mut fields).ok_or(err)?;
+                if fields.next().is_some() {
+                    return Err(err);
+                }
+                Ok(RecordedEvent {
+                    frame,
+                    timestamp,
+                    payload,
+                })
+            })
+            .collect::<std::result::Result<_, _>>()
+            .map(|events| Self { events })
+    }
+}
+
+/// A player pushes the recorded events into the event queue, with the original timing or by frames.
+#[derive(Debug, Clone)]
+pub struct EventPlayer {
+    log: EventLog,
+    cursor: usize,
+    started_ticks: Option<u32>,
+    frame: u32,
+}
+
+impl EventPlayer {
+    /// Constructs a player to play the log.
+    #[must_use]
+    pub fn new(log: EventLog) -> Self {
+        Self {
+            log,
+            cursor: 0,
+            started_ticks: None,
+            frame: 0,
+        }
+    }
+
+    /// Returns whether all the events were pushed.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.log.events.len() <= self.cursor
+    }
+
+    /// Pushes the events whose elapsed time from the first event has come, and returns the numbers of pushed events. The events rejected by the event filter are skipped and not counted. The time starts on the first call of this.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if failed to push an event.
+    pub fn push_due(&mut self, _: &EventBox) -> Result<usize> {
+        let now = unsafe { bind::SDL_GetTicks() };
+        let started = *self.started_ticks.get_or_insert(now);
+        let first = match self.log.events.first() {
+            Some(event) => event.timestamp,
+            None => return Ok(0),
+        };
+        let elapsed = now.wrapping_sub(started);
+        self.push_while(|event| event.timestamp.wrapping_sub(first) <= elapsed)
+    }
+
+    /// Pushes the events recorded in the next frame, and returns the numbers of pushed events. The events rejected by the event filter are skipped and not counted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if failed to push an event.
+    pub fn push_next_frame(&mut self, _: &EventBox) -> Result<usize> {
+        let frame = self.frame;
+        self.frame += 1;
+        self.push_while(|event| event.frame <= frame)
+    }
+
+    fn push_while(&mut self, mut predicate: impl FnMut(&RecordedEvent) -> bool) -> Result<usize> {
+        let mut pushed = 0;
+        while let Some(event) = self.log.events.get(self.cursor) {
+            if !predicate(event) {
+                break;
+            }
+            let mut raw = event.to_raw();
+            let ret = unsafe { bind::SDL_PushEvent(&mut raw) };
+            if ret < 0 {
+                return Err(SdlError::Others { msg: Sdl::error() });
+            }
+            self.cursor += 1;
+            if ret == 1 {
+                pushed += 1;
+            }
+        }
+        Ok(pushed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_log_round_trip() {
+        let log = EventLog {
+            events: vec![
+                RecordedEvent {
+                    frame: 0,
+                    timestamp: 12,
+                    payload: Payload::Key {
+                        is_pressed: true,
+                        window_id: 1,
+                        repeat: 0,
+                        scan_code: 4,
+                        key_code: 97,
+                        key_mod: 1,
+                    },
+                },
+                RecordedEvent {
+                    frame: 0,
+                    timestamp: 13,
+                    payload: Payload::TextInput {
+                        window_id: 1,
+                        text: "a b\u{3042}".into(),
+                    },
+                },
+                RecordedEvent {
+                    frame: 1,
+                    timestamp: 30,
+                    payload: Payload::MouseWheel {
+                        window_id: 1,
+                        which: 0,
+                        x: 0,
+                        y: -1,
+                        direction: 0,
+                        precise_x: 0.25,
+                        precise_y: -1.5,
+                    },
+                },
+                RecordedEvent {
+                    frame: 2,
+                    timestamp: 45,
+                    payload: Payload::Finger {
+                        ty: bind::SDL_FINGERMOTION,
+                        touch_id: -3,
+                        finger_id: 7,
+                        x: 0.1,
+                        y: 0.9,
+                        dx: 0.01,
+                        dy: -0.02,
+                        pressure: 1.0,
+                        window_id: 2,
+                    },
+                },
+                RecordedEvent {
+                    frame: 2,
+                    timestamp: 46,
+                    payload: Payload::ControllerButton {
+                        is_pressed: false,
+                        which: 0,
+                        button: 3,
+                    },
+                },
+                RecordedEvent {
+                    frame: 3,
+                    timestamp: 60,
+                    payload: Payload::Quit,
+                },
+            ],
+        };
+        let text = log.to_string();
+        assert_eq!(text.parse::<EventLog>(), Ok(log));
+    }
+
+    #[test]
+    fn event_log_rejects_invalid_line() {
+        let text = "0 1 quit\n1 2 key_down 1 0\n";
+        assert_eq!(
+            text.parse::<EventLog>(),
+            Err(ParseEventLogError { line: 2 })
+        );
+    }
+}
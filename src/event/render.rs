@@ -0,0 +1,32 @@
+//! Events on the rendering resources were lost.
+
+use crate::{bind, EnumInt};
+
+/// An event occurs on the textures of renderers must be recreated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RenderEvent {
+    /// The render targets were reset and their contents must be updated.
+    TargetsReset {
+        /// When this event occurred.
+        timestamp: u32,
+    },
+    /// The rendering device was reset and all the textures must be recreated.
+    DeviceReset {
+        /// When this event occurred.
+        timestamp: u32,
+    },
+}
+
+impl From<bind::SDL_CommonEvent> for RenderEvent {
+    fn from(raw: bind::SDL_CommonEvent) -> Self {
+        match raw.type_ as EnumInt {
+            bind::SDL_RENDER_TARGETS_RESET => Self::TargetsReset {
+                timestamp: raw.timestamp,
+            },
+            bind::SDL_RENDER_DEVICE_RESET => Self::DeviceReset {
+                timestamp: raw.timestamp,
+            },
+            _ => unreachable!(),
+        }
+    }
+}
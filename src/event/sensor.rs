@@ -15,6 +15,45 @@ pub enum SensorKind {
     Gyro,
 }
 
+impl SensorKind {
+    pub(crate) fn from_raw(raw: bind::SDL_SensorType) -> Self {
+        match raw {
+            bind::SDL_SENSOR_ACCEL => SensorKind::Accel,
+            bind::SDL_SENSOR_GYRO => SensorKind::Gyro,
+            _ => SensorKind::Others(raw as i32),
+        }
+    }
+
+    pub(crate) fn as_raw(self) -> bind::SDL_SensorType {
+        match self {
+            SensorKind::Accel => bind::SDL_SENSOR_ACCEL,
+            SensorKind::Gyro => bind::SDL_SENSOR_GYRO,
+            SensorKind::Others(raw) => raw as bind::SDL_SensorType,
+        }
+    }
+}
+
+/// An event on updated the data of a sensor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SensorEvent {
+    /// When this event occurred.
+    pub timestamp: u32,
+    /// The instance id of the sensor.
+    pub sensor_id: i32,
+    /// The updated values, the meaning depends on the kind of the sensor.
+    pub data: [f32; 6],
+}
+
+impl From<bind::SDL_SensorEvent> for SensorEvent {
+    fn from(raw: bind::SDL_SensorEvent) -> Self {
+        Self {
+            timestamp: raw.timestamp,
+            sensor_id: raw.which,
+            data: raw.data,
+        }
+    }
+}
+
 /// A sensor loaded by SDL2.
 pub struct Sensor {
     ptr: NonNull<bind::SDL_Sensor>,
@@ -6,7 +6,10 @@ use std::os::raw::c_char;
 use std::{ffi::CStr, ptr::addr_of};
 
 use crate::geo::Rect;
-use crate::{bind, Video};
+use crate::{bind, hint, Result, Video};
+
+pub mod buffer;
+pub mod ime;
 
 /// An event on input the text directly or confirm the conversion on the window.
 #[derive(Debug, Clone)]
@@ -60,6 +63,23 @@ impl From<bind::SDL_TextEditingEvent> for TextEditingEvent {
     }
 }
 
+impl TextEditingEvent {
+    /// Decodes the extended event for the long text, without freeing the text.
+    pub(crate) fn from_ext(raw: &bind::SDL_TextEditingExtEvent) -> Self {
+        Self {
+            timestamp: raw.timestamp,
+            window_id: raw.windowID,
+            text: if raw.text.is_null() {
+                String::new()
+            } else {
+                unsafe { CStr::from_ptr(raw.text) }.to_string_lossy().into()
+            },
+            start: raw.start,
+            length: raw.length,
+        }
+    }
+}
+
 /// A controller of inputting texts.
 pub struct TextInput<'video> {
     video: PhantomData<&'video Video<'video>>,
@@ -74,7 +94,7 @@ impl std::fmt::Debug for TextInput<'_> {
 assert_not_impl_all!(TextInput: Send, Sync);
 
 impl<'video> TextInput<'video> {
-    /// Starts to input the text on area `input_rect`.
+    /// Starts to input the text on area `input_rect`. Call [`TextInput::enable_extended_text`] to receive the long composition text.
     #[must_use]
     pub fn new(_: &'video Video, input_rect: Rect) -> Self {
         let mut raw_rect = input_rect.into();
@@ -84,6 +104,32 @@ impl<'video> TextInput<'video> {
         }
         Self { video: PhantomData }
     }
+
+    /// Enables [`TextEditingEvent`] to have the long composition text, otherwise it is truncated. This sets the global hint `SDL_IME_SUPPORT_EXTENDED_TEXT`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if failed to set the hint.
+    pub fn enable_extended_text(_: &'video Video) -> Result<()> {
+        hint::set_hint("SDL_IME_SUPPORT_EXTENDED_TEXT", "1")
+    }
+
+    /// Updates the area to input the text, used to place the candidate list of the input method.
+    pub fn set_rect(&self, input_rect: Rect) {
+        let mut raw_rect = input_rect.into();
+        unsafe { bind::SDL_SetTextInputRect(&mut raw_rect) }
+    }
+
+    /// Returns whether the screen keyboard or the input method is shown.
+    #[must_use]
+    pub fn is_shown(&self) -> bool {
+        unsafe { bind::SDL_IsTextInputShown() == bind::SDL_TRUE }
+    }
+
+    /// Cancels the composition in the input method.
+    pub fn clear_composition(&self) {
+        unsafe { bind::SDL_ClearComposition() }
+    }
 }
 
 impl Drop for TextInput<'_> {
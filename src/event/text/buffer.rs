@@ -0,0 +1,524 @@
+//! An editing model of the text field, with the cursor, selection and clipboard.
+
+use std::ops::Range;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{
+    clipboard::ClipboardText,
+    event::{
+        keyboard::{key_code::KeyCode, key_mod::KeyMod, KeyboardEvent},
+        Event,
+    },
+    Result,
+};
+
+use super::{ime::ImeComposition, TextEditingEvent, TextInputEvent};
+
+/// An editing model of the single or multi line text field. The cursor moves on the boundaries of grapheme clusters, and the positions are the byte offsets in the text.
+///
+/// It handles [`TextInputEvent`], [`TextEditingEvent`] and [`KeyboardEvent`] by [`TextBuffer::handle_event`]. The keyboard shortcuts are:
+///
+/// - Left/Right: moves by a grapheme, or by a word with Ctrl.
+/// - Up/Down: moves by a line on the multi line field.
+/// - Home/End: moves to the start/end of the line, or of the text with Ctrl.
+/// - Shift with the above: extends the selection.
+/// - Backspace/Delete: deletes the selection or a grapheme.
+/// - Return: inserts a new line on the multi line field.
+/// - Ctrl+A/C/X/V: selects all, copies, cuts and pastes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TextBuffer {
+    text: String,
+    cursor: usize,
+    anchor: Option<usize>,
+    is_multi_line: bool,
+    composition: ImeComposition,
+}
+
+impl TextBuffer {
+    /// Constructs an empty single line buffer, which ignores new lines.
+    #[must_use]
+    pub fn single_line() -> Self {
+        Self::default()
+    }
+
+    /// Constructs an empty multi line buffer.
+    #[must_use]
+    pub fn multi_line() -> Self {
+        Self {
+            is_multi_line: true,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the text in the buffer.
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Replaces the text and moves the cursor to the end.
+    pub fn set_text(&mut self, text: &str) {
+        self.text = self.sanitize(text);
+        self.cursor = self.text.len();
+        self.anchor = None;
+    }
+
+    /// Returns whether the buffer accepts new lines.
+    #[must_use]
+    pub fn is_multi_line(&self) -> bool {
+        self.is_multi_line
+    }
+
+    /// Returns the cursor position.
+    #[must_use]
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Returns the composition on the input method, which should be rendered at the cursor.
+    #[must_use]
+    pub fn composition(&self) -> &ImeComposition {
+        &self.composition
+    }
+
+    /// Returns the range of the selection if exists.
+    #[must_use]
+    pub fn selection(&self) -> Option<Range<usize>> {
+        let anchor = self.anchor?;
+        (anchor != self.cursor).then(|| anchor.min(self.cursor)..anchor.max(self.cursor))
+    }
+
+    /// Returns the selected text if exists.
+    #[must_use]
+    pub fn selected_text(&self) -> Option<&str> {
+        self.selection().map(|range| &self.text[range])
+    }
+
+    /// Selects all the text.
+    pub fn select_all(&mut self) {
+        self.anchor = Some(0);
+        self.cursor = self.text.len();
+    }
+
+    /// Returns the ranges of the lines, not including the new line characters.
+    pub fn lines(&self) -> impl Iterator<Item = Range<usize>> + '_ {
+        let mut start = 0;
+        self.text.split('\n').map(move |line| {
+            let range = start..start + line.len();
+            start = range.end + 1;
+            range
+        })
+    }
+
+    /// Returns the line index and the byte offset in the line of the cursor.
+    #[must_use]
+    pub fn cursor_line(&self) -> (usize, usize) {
+        let line = self.text[..self.cursor].matches('\n').count();
+        let line_start = self.line_start(self.cursor);
+        (line, self.cursor - line_start)
+    }
+
+    fn sanitize(&self, text: &str) -> String {
+        let text = text.replace("\r\n", "\n");
+        if self.is_multi_line {
+            text
+        } else {
+            text.replace('\n', " ")
+        }
+    }
+
+    fn line_start(&self, pos: usize) -> usize {
+        self.text[..pos].rfind('\n').map_or(0, |index| index + 1)
+    }
+
+    fn line_end(&self, pos: usize) -> usize {
+        self.text[pos..]
+            .find('\n')
+            .map_or(self.text.len(), |index| pos + index)
+    }
+
+    fn grapheme_start(&self, pos: usize) -> usize {
+        let pos = pos.min(self.text.len());
+        if pos == self.text.len() {
+            return pos;
+        }
+        self.text
+            .grapheme_indices(true)
+            .map(|(index, _)| index)
+            .take_while(|&index| index <= pos)
+            .last()
+            .unwrap_or(0)
+    }
+
+    fn prev_grapheme(&self, pos: usize) -> usize {
+        self.text[..pos]
+            .grapheme_indices(true)
+            .next_back()
+            .map_or(0, |(index, _)| index)
+    }
+
+    fn next_grapheme(&self, pos: usize) -> usize {
+        self.text[pos..]
+            .graphemes(true)
+            .next()
+            .map_or(pos, |grapheme| pos + grapheme.len())
+    }
+
+    fn prev_word(&self, pos: usize) -> usize {
+        self.text[..pos]
+            .split_word_bound_indices()
+            .rev()
+            .find(|(_, word)| !word.trim().is_empty())
+            .map_or(0, |(index, _)| index)
+    }
+
+    fn next_word(&self, pos: usize) -> usize {
+        self.text[pos..]
+            .split_word_bound_indices()
+            .find(|(_, word)| !word.trim().is_empty())
+            .map_or(self.text.len(), |(index, word)| pos + index + word.len())
+    }
+
+    /// Moves the cursor to `pos` snapped to the grapheme boundary, and extends the selection if `extend`.
+    pub fn move_to(&mut self, pos: usize, extend: bool) {
+        let pos = self.grapheme_start(pos);
+        if extend {
+            self.anchor.get_or_insert(self.cursor);
+        } else {
+            self.anchor = None;
+        }
+        self.cursor = pos;
+    }
+
+    fn move_horizontally(&mut self, forward: bool, by_word: bool, extend: bool) {
+        let pos = match (self.selection(), forward, extend) {
+            (Some(range), false, false) if !by_word => range.start,
+            (Some(range), true, false) if !by_word => range.end,
+            (_, false, _) if by_word => self.prev_word(self.cursor),
+            (_, true, _) if by_word => self.next_word(self.cursor),
+            (_, false, _) => self.prev_grapheme(self.cursor),
+            (_, true, _) => self.next_grapheme(self.cursor),
+        };
+        self.move_to(pos, extend);
+    }
+
+    fn move_vertically(&mut self, down: bool, extend: bool) {
+        let line_start = self.line_start(self.cursor);
+        let column = self.text[line_start..self.cursor].graphemes(true).count();
+        let target_start = if down {
+            let line_end = self.line_end(self.cursor);
+            if line_end == self.text.len() {
+                self.move_to(line_end, extend);
+                return;
+            }
+            line_end + 1
+        } else {
+            if line_start == 0 {
+                self.move_to(0, extend);
+                return;
+            }
+            self.line_start(line_start - 1)
+        };
+        let target_end = self.line_end(target_start);
+        let pos = self.text[target_start..target_end]
+            .grapheme_indices(true)
+            .nth(column)
+            .map_or(target_end, |(index, _)| target_start + index);
+        self.move_to(pos, extend);
+    }
+
+    /// Inserts the text at the cursor, replacing the selection.
+    pub fn insert(&mut self, text: &str) {
+        let text = self.sanitize(text);
+        let range = self.selection().unwrap_or(self.cursor..self.cursor);
+        self.text.replace_range(range.clone(), &text);
+        self.cursor = range.start + text.len();
+        self.anchor = None;
+    }
+
+    fn delete(&mut self, forward: bool) {
+        let range = self.selection().unwrap_or_else(|| {
+            if forward {
+                self.cursor..self.next_grapheme(self.cursor)
+            } else {
+                self.prev_grapheme(self.cursor)..self.cursor
+            }
+        });
+        self.text.replace_range(range.clone(), "");
+        self.cursor = range.start;
+        self.anchor = None;
+    }
+
+    /// Deletes the selection, or the grapheme before the cursor.
+    pub fn delete_backward(&mut self) {
+        self.delete(false);
+    }
+
+    /// Deletes the selection, or the grapheme after the cursor.
+    pub fn delete_forward(&mut self) {
+        self.delete(true);
+    }
+
+    /// Copies the selection into the clipboard.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the selection contains a null character, or failed to set the clipboard.
+    pub fn copy(&self) -> Result<()> {
+        if let Some(text) = self.selected_text() {
+            ClipboardText::set(text)?;
+        }
+        Ok(())
+    }
+
+    /// Cuts the selection into the clipboard. The selection is kept if failed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the selection contains a null character, or failed to set the clipboard.
+    pub fn cut(&mut self) -> Result<()> {
+        if self.selection().is_some() {
+            self.copy()?;
+            self.delete(false);
+        }
+        Ok(())
+    }
+
+    /// Pastes the text in the clipboard at the cursor, replacing the selection.
+    pub fn paste(&mut self) {
+        if let Some(clipboard) = ClipboardText::new() {
+            self.insert(clipboard.text());
+        }
+    }
+
+    /// Handles the text input event.
+    pub fn handle_input(&mut self, event: &TextInputEvent) {
+        self.composition.handle_input(event);
+        let committed = self.composition.take_committed();
+        self.insert(&committed);
+    }
+
+    /// Handles the text editing event.
+    pub fn handle_editing(&mut self, event: &TextEditingEvent) {
+        self.composition.handle_editing(event);
+    }
+
+    /// Handles the keyboard event, and returns whether it was used. The keys are ignored while composing on the input method.
+    pub fn handle_keyboard(&mut self, event: &KeyboardEvent) -> bool {
+        if !event.is_pressed || self.composition.is_composing() {
+            return false;
+        }
+        let key_mod = event.symbol.key_mod;
+        let extend = key_mod.intersects(KeyMod::SHIFT);
+        let ctrl = key_mod.intersects(KeyMod::CTRL | KeyMod::GUI);
+        match event.symbol.key_code {
+            KeyCode::Left => self.move_horizontally(false, ctrl, extend),
+            KeyCode::Right => self.move_horizontally(true, ctrl, extend),
+            KeyCode::Up if self.is_multi_line => self.move_vertically(false, extend),
+            KeyCode::Down if self.is_multi_line => self.move_vertically(true, extend),
+            KeyCode::Home if ctrl => self.move_to(0, extend),
+            KeyCode::Home => self.move_to(self.line_start(self.cursor), extend),
+            KeyCode::End if ctrl => self.move_to(self.text.len(), extend),
+            KeyCode::End => self.move_to(self.line_end(self.cursor), extend),
+            KeyCode::Backspace => self.delete_backward(),
+            KeyCode::Delete => self.delete_forward(),
+            KeyCode::Return | KeyCode::KeypadEnter if self.is_multi_line => self.insert("\n"),
+            KeyCode::A if ctrl => self.select_all(),
+            KeyCode::C if ctrl => return self.copy().is_ok(),
+            KeyCode::X if ctrl => return self.cut().is_ok(),
+            KeyCode::V if ctrl => self.paste(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Handles the event related on the text input, and returns whether it was used.
+    pub fn handle_event(&mut self, event: &Event) -> bool {
+        match event {
+            Event::Input(input) => self.handle_input(input),
+            Event::Editing(editing) => self.handle_editing(editing),
+            Event::Keyboard(keyboard) => return self.handle_keyboard(keyboard),
+            _ => return false,
+        }
+        true
+    }
+}
+
+#[cfg(feature = "ttf")]
+mod layout {
+    use super::TextBuffer;
+    use crate::{
+        geo::Point,
+        ttf::font::{Font, MetricExt},
+        Result,
+    };
+
+    impl TextBuffer {
+        fn line_width(font: &Font, line: &str) -> Result<u32> {
+            if line.is_empty() {
+                return Ok(0);
+            }
+            let (width, _) = font.rendered_width(line, u32::MAX / 2)?;
+            Ok(width)
+        }
+
+        /// Returns the position of the cursor laid out with the font, relative to the top left of the text. The height of a line is [`MetricExt::line_skip`].
+        ///
+        /// # Errors
+        ///
+        /// Returns `Err` if failed to measure the text.
+        pub fn cursor_position(&self, font: &Font) -> Result<Point> {
+            let (line, offset) = self.cursor_line();
+            let line_start = self.cursor - offset;
+            let x = Self::line_width(font, &self.text[line_start..self.cursor])?;
+            Ok(Point {
+                x: x as i32,
+                y: (line as u32 * font.line_skip()) as i32,
+            })
+        }
+
+        /// Returns the position in the text at the point laid out with the font, relative to the top left of the text.
+        ///
+        /// # Errors
+        ///
+        /// Returns `Err` if failed to measure the text.
+        pub fn hit_test(&self, font: &Font, point: Point) -> Result<usize> {
+            let line_skip = font.line_skip().max(1) as i32;
+            let line_index = (point.y.max(0) / line_skip) as usize;
+            let line = match self.lines().nth(line_index) {
+                Some(line) => line,
+                None => return Ok(self.text.len()),
+            };
+            let text = &self.text[line.clone()];
+            let x = point.x.max(0) as u32;
+            let (_, count) = font.rendered_width(text, x)?;
+            let pos = text
+                .char_indices()
+                .nth(count)
+                .map_or(line.end, |(index, _)| line.start + index);
+            let mut pos = self.grapheme_start(pos).max(line.start);
+            let next = self.next_grapheme(pos).min(line.end);
+            if pos < line.end {
+                let before = Self::line_width(font, &self.text[line.start..pos])?;
+                let after = Self::line_width(font, &self.text[line.start..next])?;
+                if after.saturating_sub(x) < x.saturating_sub(before) {
+                    pos = next;
+                }
+            }
+            Ok(pos)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::keyboard::{scan_code::ScanCode, KeySymbol};
+
+    fn press(buffer: &mut TextBuffer, key_code: KeyCode, key_mod: KeyMod) -> bool {
+        buffer.handle_keyboard(&KeyboardEvent {
+            timestamp: 0,
+            window_id: 1,
+            is_pressed: true,
+            is_repeated: false,
+            symbol: KeySymbol {
+                scan_code: ScanCode::Unknown,
+                key_code,
+                key_mod,
+            },
+        })
+    }
+
+    #[test]
+    fn insert_and_select() {
+        let mut buffer = TextBuffer::single_line();
+        buffer.insert("hello\nworld");
+        assert_eq!(buffer.text(), "hello world");
+        assert_eq!(buffer.cursor(), 11);
+
+        press(&mut buffer, KeyCode::Left, KeyMod::LCTRL);
+        assert_eq!(buffer.cursor(), 6);
+        press(&mut buffer, KeyCode::End, KeyMod::LSHIFT);
+        assert_eq!(buffer.selected_text(), Some("world"));
+
+        buffer.insert("rust");
+        assert_eq!(buffer.text(), "hello rust");
+        assert_eq!(buffer.selection(), None);
+
+        press(&mut buffer, KeyCode::A, KeyMod::LCTRL);
+        press(&mut buffer, KeyCode::Backspace, KeyMod::NONE);
+        assert_eq!(buffer.text(), "");
+        assert_eq!(buffer.cursor(), 0);
+    }
+
+    #[test]
+    fn multi_line_moves() {
+        let mut buffer = TextBuffer::multi_line();
+        buffer.set_text("abc\r\nde\nfghi");
+        assert_eq!(buffer.lines().collect::<Vec<_>>(), vec![0..3, 4..6, 7..11]);
+
+        buffer.move_to(2, false);
+        press(&mut buffer, KeyCode::Down, KeyMod::NONE);
+        assert_eq!(buffer.cursor_line(), (1, 2));
+        press(&mut buffer, KeyCode::Down, KeyMod::NONE);
+        assert_eq!(buffer.cursor_line(), (2, 2));
+        press(&mut buffer, KeyCode::Home, KeyMod::NONE);
+        assert_eq!(buffer.cursor(), 7);
+        press(&mut buffer, KeyCode::Up, KeyMod::LSHIFT);
+        assert_eq!(buffer.selected_text(), Some("de\n"));
+    }
+
+    #[test]
+    fn multi_codepoint_graphemes() {
+        let family = "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}";
+        let accent = "e\u{301}";
+        let flag = "\u{1f1ef}\u{1f1f5}";
+        let mut buffer = TextBuffer::single_line();
+        buffer.set_text(&format!("{}{}{}", accent, family, flag));
+
+        press(&mut buffer, KeyCode::Left, KeyMod::NONE);
+        assert_eq!(buffer.cursor(), accent.len() + family.len());
+        press(&mut buffer, KeyCode::Left, KeyMod::NONE);
+        assert_eq!(buffer.cursor(), accent.len());
+        press(&mut buffer, KeyCode::Left, KeyMod::NONE);
+        assert_eq!(buffer.cursor(), 0);
+        press(&mut buffer, KeyCode::Right, KeyMod::NONE);
+        assert_eq!(buffer.cursor(), accent.len());
+
+        buffer.move_to(accent.len() + "\u{1f468}".len(), false);
+        assert_eq!(buffer.cursor(), accent.len());
+        buffer.move_to(1, false);
+        assert_eq!(buffer.cursor(), 0);
+
+        buffer.move_to(accent.len(), false);
+        buffer.delete_forward();
+        assert_eq!(buffer.text(), format!("{}{}", accent, flag));
+        buffer.move_to(buffer.text().len(), false);
+        buffer.delete_backward();
+        assert_eq!(buffer.text(), accent);
+        buffer.delete_backward();
+        assert_eq!(buffer.text(), "");
+    }
+
+    #[test]
+    fn keys_are_ignored_while_composing() {
+        let mut buffer = TextBuffer::single_line();
+        buffer.handle_editing(&TextEditingEvent {
+            timestamp: 0,
+            window_id: 1,
+            text: "か".into(),
+            start: 1,
+            length: 0,
+        });
+        assert!(!press(&mut buffer, KeyCode::Backspace, KeyMod::NONE));
+        buffer.handle_input(&TextInputEvent {
+            timestamp: 0,
+            window_id: 1,
+            text: "蚊".into(),
+        });
+        assert_eq!(buffer.text(), "蚊");
+        assert!(!buffer.composition().is_composing());
+        assert!(press(&mut buffer, KeyCode::Backspace, KeyMod::NONE));
+        assert_eq!(buffer.text(), "");
+    }
+}
@@ -0,0 +1,162 @@
+//! Tracking the composition on the input method.
+
+use crate::event::Event;
+
+use super::{TextEditingEvent, TextInputEvent};
+
+/// A state of the composition on the input method, made from [`TextEditingEvent`] and [`TextInputEvent`].
+///
+/// The preedit string is the text being composed, and the committed string is the text confirmed but not taken yet.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImeComposition {
+    preedit: String,
+    cursor: usize,
+    selection_length: usize,
+    committed: String,
+}
+
+impl ImeComposition {
+    /// Constructs an empty composition.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Updates the preedit string by the editing event.
+    pub fn handle_editing(&mut self, event: &TextEditingEvent) {
+        self.preedit = event.text.clone();
+        let chars = self.preedit.chars().count();
+        self.cursor = (event.start.max(0) as usize).min(chars);
+        self.selection_length = (event.length.max(0) as usize).min(chars - self.cursor);
+    }
+
+    /// Commits the text by the input event, and clears the preedit string.
+    pub fn handle_input(&mut self, event: &TextInputEvent) {
+        self.preedit.clear();
+        self.cursor = 0;
+        self.selection_length = 0;
+        self.committed.push_str(&event.text);
+    }
+
+    /// Updates the state if the event is [`Event::Editing`] or [`Event::Input`], and returns whether it was used.
+    pub fn handle_event(&mut self, event: &Event) -> bool {
+        match event {
+            Event::Editing(editing) => self.handle_editing(editing),
+            Event::Input(input) => self.handle_input(input),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Returns the text being composed.
+    #[must_use]
+    pub fn preedit(&self) -> &str {
+        &self.preedit
+    }
+
+    /// Returns whether the text is being composed.
+    #[must_use]
+    pub fn is_composing(&self) -> bool {
+        !self.preedit.is_empty()
+    }
+
+    /// Returns the cursor position in the preedit string, counted in characters.
+    #[must_use]
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Returns the cursor position in the preedit string, counted in bytes.
+    #[must_use]
+    pub fn cursor_byte_offset(&self) -> usize {
+        self.preedit
+            .char_indices()
+            .nth(self.cursor)
+            .map_or(self.preedit.len(), |(offset, _)| offset)
+    }
+
+    /// Returns the length of the selected characters from the cursor in the preedit string.
+    #[must_use]
+    pub fn selection_length(&self) -> usize {
+        self.selection_length
+    }
+
+    /// Returns the committed text not taken yet.
+    #[must_use]
+    pub fn committed(&self) -> &str {
+        &self.committed
+    }
+
+    /// Takes the committed text out.
+    pub fn take_committed(&mut self) -> String {
+        std::mem::take(&mut self.committed)
+    }
+
+    /// Clears the preedit string, such as after [`super::TextInput::clear_composition`].
+    pub fn clear_preedit(&mut self) {
+        self.preedit.clear();
+        self.cursor = 0;
+        self.selection_length = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn editing(text: &str, start: i32, length: i32) -> TextEditingEvent {
+        TextEditingEvent {
+            timestamp: 0,
+            window_id: 1,
+            text: text.into(),
+            start,
+            length,
+        }
+    }
+
+    fn input(text: &str) -> TextInputEvent {
+        TextInputEvent {
+            timestamp: 0,
+            window_id: 1,
+            text: text.into(),
+        }
+    }
+
+    #[test]
+    fn preedit_then_commit() {
+        let mut composition = ImeComposition::new();
+        assert!(!composition.is_composing());
+
+        composition.handle_editing(&editing("にほn", 3, 0));
+        assert!(composition.is_composing());
+        assert_eq!(composition.preedit(), "にほn");
+        assert_eq!(composition.cursor(), 3);
+        assert_eq!(composition.cursor_byte_offset(), "にほn".len());
+
+        composition.handle_editing(&editing("日本語", 1, 2));
+        assert_eq!(composition.cursor(), 1);
+        assert_eq!(composition.cursor_byte_offset(), "日".len());
+        assert_eq!(composition.selection_length(), 2);
+
+        assert!(composition.handle_event(&Event::Input(input("日本語"))));
+        assert!(!composition.is_composing());
+        assert_eq!(composition.cursor(), 0);
+        assert_eq!(composition.take_committed(), "日本語");
+        assert_eq!(composition.committed(), "");
+    }
+
+    #[test]
+    fn out_of_range_positions_are_clamped() {
+        let mut composition = ImeComposition::new();
+        composition.handle_editing(&editing("ab", 5, 3));
+        assert_eq!(composition.cursor(), 2);
+        assert_eq!(composition.selection_length(), 0);
+
+        composition.handle_editing(&editing("abc", -1, 10));
+        assert_eq!(composition.cursor(), 0);
+        assert_eq!(composition.selection_length(), 3);
+
+        composition.clear_preedit();
+        assert_eq!(composition, ImeComposition::new());
+    }
+}
@@ -3,9 +3,10 @@
 use static_assertions::assert_not_impl_all;
 use std::{cell::Cell, marker::PhantomData, ptr::NonNull};
 
-use crate::{bind, file::RwOps, Result, Sdl, SdlError};
+use crate::{bind, file::RwOps, EnumInt, Result, Sdl, SdlError};
 
 pub mod gesture;
+pub mod recognizer;
 
 /// A finger input, which having coordinates and pressures.
 pub struct TouchFinger<'device> {
@@ -44,6 +45,64 @@ impl TouchFinger<'_> {
     }
 }
 
+/// A kind of [`TouchFingerEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FingerEventKind {
+    /// The finger was touched.
+    Down,
+    /// The finger was moved.
+    Motion,
+    /// The finger was released.
+    Up,
+}
+
+/// An event on a finger was touched, moved or released on a touch device.
+#[derive(Debug, Clone)]
+pub struct TouchFingerEvent {
+    /// When this event occurred.
+    pub timestamp: u32,
+    /// The kind of the event.
+    pub kind: FingerEventKind,
+    /// The touch device the finger is on.
+    pub touch: TouchDevice,
+    /// The id of the finger.
+    pub finger_id: i64,
+    /// The normalized x coord of the finger, in `0.0..=1.0`.
+    pub x: f32,
+    /// The normalized y coord of the finger, in `0.0..=1.0`.
+    pub y: f32,
+    /// The normalized moved amount of x coord, in `-1.0..=1.0`.
+    pub dx: f32,
+    /// The normalized moved amount of y coord, in `-1.0..=1.0`.
+    pub dy: f32,
+    /// The normalized pressure of the finger, in `0.0..=1.0`.
+    pub pressure: f32,
+    /// The id of the window touched, or `0` if not available.
+    pub window_id: u32,
+}
+
+impl From<bind::SDL_TouchFingerEvent> for TouchFingerEvent {
+    fn from(raw: bind::SDL_TouchFingerEvent) -> Self {
+        Self {
+            timestamp: raw.timestamp,
+            kind: match raw.type_ as EnumInt {
+                bind::SDL_FINGERDOWN => FingerEventKind::Down,
+                bind::SDL_FINGERMOTION => FingerEventKind::Motion,
+                bind::SDL_FINGERUP => FingerEventKind::Up,
+                _ => unreachable!(),
+            },
+            touch: TouchDevice(raw.touchId, PhantomData),
+            finger_id: raw.fingerId,
+            x: raw.x,
+            y: raw.y,
+            dx: raw.dx,
+            dy: raw.dy,
+            pressure: raw.pressure,
+            window_id: raw.windowID,
+        }
+    }
+}
+
 /// A device that receives the finger input.
 #[derive(Debug, Clone)]
 pub struct TouchDevice(bind::SDL_TouchID, PhantomData<Cell<u8>>);
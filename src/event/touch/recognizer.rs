@@ -0,0 +1,645 @@
+//! Recognizers of the common touch gestures from the finger events.
+
+use std::collections::{BTreeMap, VecDeque};
+
+use super::{FingerEventKind, TouchFingerEvent};
+
+/// A phase of the continuous gesture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GesturePhase {
+    /// The gesture was started.
+    Began,
+    /// The gesture was updated.
+    Changed,
+    /// The gesture was finished.
+    Ended,
+}
+
+/// A direction of the swipe gesture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwipeDirection {
+    /// Swiped to the up.
+    Up,
+    /// Swiped to the down.
+    Down,
+    /// Swiped to the left.
+    Left,
+    /// Swiped to the right.
+    Right,
+}
+
+/// A gesture recognized by [`GestureRecognizer`]. The coordinates are normalized in `0.0..=1.0` as same as [`TouchFingerEvent`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum TouchGesture {
+    /// A finger was touched and released quickly.
+    Tap {
+        /// When this gesture was recognized.
+        timestamp: u32,
+        /// The x coord of the tap.
+        x: f32,
+        /// The y coord of the tap.
+        y: f32,
+    },
+    /// A finger was tapped twice quickly, it follows [`TouchGesture::Tap`] of the second tap.
+    DoubleTap {
+        /// When this gesture was recognized.
+        timestamp: u32,
+        /// The x coord of the tap.
+        x: f32,
+        /// The y coord of the tap.
+        y: f32,
+    },
+    /// A finger was touched for a while without moving.
+    LongPress {
+        /// When this gesture was recognized.
+        timestamp: u32,
+        /// The x coord of the finger.
+        x: f32,
+        /// The y coord of the finger.
+        y: f32,
+    },
+    /// A finger was moved and released quickly.
+    Swipe {
+        /// When this gesture was recognized.
+        timestamp: u32,
+        /// The direction of the swipe.
+        direction: SwipeDirection,
+        /// The x coord of the start point.
+        x: f32,
+        /// The y coord of the start point.
+        y: f32,
+        /// The speed of the swipe, in the normalized distance per second.
+        speed: f32,
+    },
+    /// A finger is being dragged.
+    Pan {
+        /// When this gesture was recognized.
+        timestamp: u32,
+        /// The phase of the gesture.
+        phase: GesturePhase,
+        /// The x coord of the finger.
+        x: f32,
+        /// The y coord of the finger.
+        y: f32,
+        /// The moved amount of x coord from the previous.
+        dx: f32,
+        /// The moved amount of y coord from the previous.
+        dy: f32,
+    },
+    /// Two fingers are being pinched or rotated.
+    PinchRotate {
+        /// When this gesture was recognized.
+        timestamp: u32,
+        /// The phase of the gesture.
+        phase: GesturePhase,
+        /// The x coord of the center of the fingers.
+        center_x: f32,
+        /// The y coord of the center of the fingers.
+        center_y: f32,
+        /// The ratio of the distance between the fingers from the start.
+        scale: f32,
+        /// The rotated angle from the start in radians, the clockwise is positive.
+        rotation: f32,
+    },
+}
+
+/// A configuration of the thresholds for [`GestureRecognizer`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GestureConfig {
+    /// The maximum distance treated as not moved.
+    pub tap_slop: f32,
+    /// The maximum duration of a tap in milliseconds.
+    pub tap_max_ms: u32,
+    /// The maximum interval between two taps of a double tap in milliseconds.
+    pub double_tap_interval_ms: u32,
+    /// The minimum duration of a long press in milliseconds.
+    pub long_press_ms: u32,
+    /// The minimum distance of a swipe.
+    pub swipe_min_distance: f32,
+    /// The maximum duration of a swipe in milliseconds.
+    pub swipe_max_ms: u32,
+}
+
+impl Default for GestureConfig {
+    fn default() -> Self {
+        Self {
+            tap_slop: 0.02,
+            tap_max_ms: 250,
+            double_tap_interval_ms: 300,
+            long_press_ms: 500,
+            swipe_min_distance: 0.1,
+            swipe_max_ms: 300,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct FingerTrack {
+    start_x: f32,
+    start_y: f32,
+    start_time: u32,
+    x: f32,
+    y: f32,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Pinch {
+    distance: f32,
+    angle: f32,
+}
+
+/// Returns the angle from `start` to `current` wrapped in `(-PI, PI]`.
+fn rotation_between(start: f32, current: f32) -> f32 {
+    use std::f32::consts::{PI, TAU};
+    let diff = (current - start).rem_euclid(TAU);
+    if PI < diff {
+        diff - TAU
+    } else {
+        diff
+    }
+}
+
+/// A recognizer of the touch gestures. Feed the finger events by [`GestureRecognizer::feed`], call [`GestureRecognizer::update`] once a frame to detect long presses, and take the recognized gestures by [`GestureRecognizer::poll`].
+///
+/// The synthetic finger inputs can be fed by [`GestureRecognizer::feed_finger`].
+#[derive(Debug, Clone, Default)]
+pub struct GestureRecognizer {
+    config: GestureConfig,
+    fingers: BTreeMap<(i64, i64), FingerTrack>,
+    is_moved: bool,
+    is_multi: bool,
+    is_long_pressed: bool,
+    is_panning: bool,
+    pinch: Option<Pinch>,
+    last_tap: Option<(u32, f32, f32)>,
+    recognized: VecDeque<TouchGesture>,
+}
+
+impl GestureRecognizer {
+    /// Constructs a recognizer with the default configuration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Constructs a recognizer with the configuration.
+    #[must_use]
+    pub fn with_config(config: GestureConfig) -> Self {
+        Self {
+            config,
+            ..Self::default()
+        }
+    }
+
+    /// Feeds the finger event.
+    pub fn feed(&mut self, event: &TouchFingerEvent) {
+        self.feed_finger(
+            event.timestamp,
+            event.kind,
+            event.touch.0,
+            event.finger_id,
+            event.x,
+            event.y,
+        );
+    }
+
+    /// Feeds the finger input with the normalized coordinates. The finger is identified by the pair of `touch_id` of the device and `finger_id`.
+    pub fn feed_finger(
+        &mut self,
+        timestamp: u32,
+        kind: FingerEventKind,
+        touch_id: i64,
+        finger_id: i64,
+        x: f32,
+        y: f32,
+    ) {
+        let finger = (touch_id, finger_id);
+        match kind {
+            FingerEventKind::Down => self.on_down(timestamp, finger, x, y),
+            FingerEventKind::Motion => self.on_motion(timestamp, finger, x, y),
+            FingerEventKind::Up => self.on_up(timestamp, finger, x, y),
+        }
+    }
+
+    /// Detects the long press at `now` in milliseconds, such as [`crate::Ticks::now`].
+    pub fn update(&mut self, now: u32) {
+        if self.is_moved || self.is_multi || self.is_long_pressed {
+            return;
+        }
+        if let Some(finger) = self.single_finger() {
+            if self.config.long_press_ms <= now.wrapping_sub(finger.start_time) {
+                self.is_long_pressed = true;
+                self.recognized.push_back(TouchGesture::LongPress {
+                    timestamp: now,
+                    x: finger.x,
+                    y: finger.y,
+                });
+            }
+        }
+    }
+
+    /// Takes a recognized gesture in the order of recognition.
+    pub fn poll(&mut self) -> Option<TouchGesture> {
+        self.recognized.pop_front()
+    }
+
+    fn single_finger(&self) -> Option<FingerTrack> {
+        if self.fingers.len() == 1 {
+            self.fingers.values().next().copied()
+        } else {
+            None
+        }
+    }
+
+    fn two_fingers(&self) -> Option<(FingerTrack, FingerTrack)> {
+        let mut fingers = self.fingers.values();
+        match (fingers.next(), fingers.next(), fingers.next()) {
+            (Some(&first), Some(&second), None) => Some((first, second)),
+            _ => None,
+        }
+    }
+
+    fn pinch_of(first: &FingerTrack, second: &FingerTrack) -> Pinch {
+        let (dx, dy) = (second.x - first.x, second.y - first.y);
+        Pinch {
+            distance: dx.hypot(dy),
+            angle: dy.atan2(dx),
+        }
+    }
+
+    fn end_pan(&mut self, timestamp: u32, x: f32, y: f32) {
+        if self.is_panning {
+            self.is_panning = false;
+            self.recognized.push_back(TouchGesture::Pan {
+                timestamp,
+                phase: GesturePhase::Ended,
+                x,
+                y,
+                dx: 0.0,
+                dy: 0.0,
+            });
+        }
+    }
+
+    fn end_pinch(&mut self, timestamp: u32) {
+        if let (Some(start), Some((first, second))) = (self.pinch.take(), self.two_fingers()) {
+            let current = Self::pinch_of(&first, &second);
+            self.recognized.push_back(TouchGesture::PinchRotate {
+                timestamp,
+                phase: GesturePhase::Ended,
+                center_x: (first.x + second.x) / 2.0,
+                center_y: (first.y + second.y) / 2.0,
+                scale: current.distance / start.distance.max(f32::EPSILON),
+                rotation: rotation_between(start.angle, current.angle),
+            });
+        }
+    }
+
+    fn on_down(&mut self, timestamp: u32, finger_id: (i64, i64), x: f32, y: f32) {
+        if self.fingers.is_empty() {
+            self.is_moved = false;
+            self.is_multi = false;
+            self.is_long_pressed = false;
+        } else {
+            self.is_multi = true;
+            if let Some(finger) = self.single_finger() {
+                self.end_pan(timestamp, finger.x, finger.y);
+            }
+            self.end_pinch(timestamp);
+        }
+        self.fingers.insert(
+            finger_id,
+            FingerTrack {
+                start_x: x,
+                start_y: y,
+                start_time: timestamp,
+                x,
+                y,
+            },
+        );
+        if let Some((first, second)) = self.two_fingers() {
+            self.pinch = Some(Self::pinch_of(&first, &second));
+            self.recognized.push_back(TouchGesture::PinchRotate {
+                timestamp,
+                phase: GesturePhase::Began,
+                center_x: (first.x + second.x) / 2.0,
+                center_y: (first.y + second.y) / 2.0,
+                scale: 1.0,
+                rotation: 0.0,
+            });
+        }
+    }
+
+    fn on_motion(&mut self, timestamp: u32, finger_id: (i64, i64), x: f32, y: f32) {
+        let finger = match self.fingers.get_mut(&finger_id) {
+            Some(finger) => finger,
+            None => return,
+        };
+        let (dx, dy) = (x - finger.x, y - finger.y);
+        finger.x = x;
+        finger.y = y;
+        let moved_distance = (x - finger.start_x).hypot(y - finger.start_y);
+        if self.config.tap_slop < moved_distance {
+            self.is_moved = true;
+        }
+        if let (Some(start), Some((first, second))) = (self.pinch, self.two_fingers()) {
+            let current = Self::pinch_of(&first, &second);
+            self.recognized.push_back(TouchGesture::PinchRotate {
+                timestamp,
+                phase: GesturePhase::Changed,
+                center_x: (first.x + second.x) / 2.0,
+                center_y: (first.y + second.y) / 2.0,
+                scale: current.distance / start.distance.max(f32::EPSILON),
+                rotation: rotation_between(start.angle, current.angle),
+            });
+            return;
+        }
+        if self.is_multi || !self.is_moved || self.fingers.len() != 1 {
+            return;
+        }
+        let phase = if self.is_panning {
+            GesturePhase::Changed
+        } else {
+            self.is_panning = true;
+            GesturePhase::Began
+        };
+        self.recognized.push_back(TouchGesture::Pan {
+            timestamp,
+            phase,
+            x,
+            y,
+            dx,
+            dy,
+        });
+    }
+
+    fn on_up(&mut self, timestamp: u32, finger_id: (i64, i64), x: f32, y: f32) {
+        if !self.fingers.contains_key(&finger_id) {
+            return;
+        }
+        self.end_pinch(timestamp);
+        let finger = match self.fingers.remove(&finger_id) {
+            Some(finger) => finger,
+            None => return,
+        };
+        self.end_pan(timestamp, x, y);
+        if self.is_multi || !self.fingers.is_empty() {
+            return;
+        }
+        let duration = timestamp.wrapping_sub(finger.start_time);
+        let (dx, dy) = (x - finger.start_x, y - finger.start_y);
+        let distance = dx.hypot(dy);
+        if !self.is_moved && !self.is_long_pressed && duration <= self.config.tap_max_ms {
+            self.recognized
+                .push_back(TouchGesture::Tap { timestamp, x, y });
+            let is_double = self.last_tap.map_or(false, |(last, last_x, last_y)| {
+                timestamp.wrapping_sub(last) <= self.config.double_tap_interval_ms
+                    && (x - last_x).hypot(y - last_y) <= self.config.tap_slop * 2.0
+            });
+            if is_double {
+                self.last_tap = None;
+                self.recognized
+                    .push_back(TouchGesture::DoubleTap { timestamp, x, y });
+            } else {
+                self.last_tap = Some((timestamp, x, y));
+            }
+        } else if self.config.swipe_min_distance <= distance && duration <= self.config.swipe_max_ms
+        {
+            let direction = if dy.abs() < dx.abs() {
+                if 0.0 < dx {
+                    SwipeDirection::Right
+                } else {
+                    SwipeDirection::Left
+                }
+            } else if 0.0 < dy {
+                SwipeDirection::Down
+            } else {
+                SwipeDirection::Up
+            };
+            self.recognized.push_back(TouchGesture::Swipe {
+                timestamp,
+                direction,
+                x: finger.start_x,
+                y: finger.start_y,
+                speed: distance * 1000.0 / duration.max(1) as f32,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::marker::PhantomData;
+
+    use super::*;
+    use crate::event::touch::TouchDevice;
+
+    fn finger(
+        timestamp: u32,
+        kind: FingerEventKind,
+        finger_id: i64,
+        x: f32,
+        y: f32,
+    ) -> TouchFingerEvent {
+        finger_on(0, timestamp, kind, finger_id, x, y)
+    }
+
+    fn finger_on(
+        touch_id: i64,
+        timestamp: u32,
+        kind: FingerEventKind,
+        finger_id: i64,
+        x: f32,
+        y: f32,
+    ) -> TouchFingerEvent {
+        TouchFingerEvent {
+            timestamp,
+            kind,
+            touch: TouchDevice(touch_id, PhantomData),
+            finger_id,
+            x,
+            y,
+            dx: 0.0,
+            dy: 0.0,
+            pressure: 1.0,
+            window_id: 0,
+        }
+    }
+
+    fn feed_all(
+        recognizer: &mut GestureRecognizer,
+        events: &[TouchFingerEvent],
+    ) -> Vec<TouchGesture> {
+        for event in events {
+            recognizer.feed(event);
+        }
+        std::iter::from_fn(|| recognizer.poll()).collect()
+    }
+
+    #[test]
+    fn tap_and_double_tap() {
+        let mut recognizer = GestureRecognizer::new();
+        let gestures = feed_all(
+            &mut recognizer,
+            &[
+                finger(0, FingerEventKind::Down, 1, 0.5, 0.5),
+                finger(50, FingerEventKind::Up, 1, 0.5, 0.5),
+                finger(150, FingerEventKind::Down, 1, 0.5, 0.5),
+                finger(200, FingerEventKind::Up, 1, 0.505, 0.5),
+            ],
+        );
+        assert_eq!(
+            gestures,
+            vec![
+                TouchGesture::Tap {
+                    timestamp: 50,
+                    x: 0.5,
+                    y: 0.5
+                },
+                TouchGesture::Tap {
+                    timestamp: 200,
+                    x: 0.505,
+                    y: 0.5
+                },
+                TouchGesture::DoubleTap {
+                    timestamp: 200,
+                    x: 0.505,
+                    y: 0.5
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn long_press() {
+        let mut recognizer = GestureRecognizer::new();
+        recognizer.feed(&finger(0, FingerEventKind::Down, 1, 0.2, 0.3));
+        recognizer.update(100);
+        assert_eq!(recognizer.poll(), None);
+        recognizer.update(600);
+        assert_eq!(
+            recognizer.poll(),
+            Some(TouchGesture::LongPress {
+                timestamp: 600,
+                x: 0.2,
+                y: 0.3
+            })
+        );
+        recognizer.feed(&finger(700, FingerEventKind::Up, 1, 0.2, 0.3));
+        assert_eq!(recognizer.poll(), None);
+    }
+
+    #[test]
+    fn pan_and_swipe() {
+        let mut recognizer = GestureRecognizer::new();
+        let gestures = feed_all(
+            &mut recognizer,
+            &[
+                finger(0, FingerEventKind::Down, 1, 0.2, 0.5),
+                finger(50, FingerEventKind::Motion, 1, 0.4, 0.5),
+                finger(100, FingerEventKind::Up, 1, 0.6, 0.5),
+            ],
+        );
+        assert!(matches!(
+            gestures[0],
+            TouchGesture::Pan {
+                phase: GesturePhase::Began,
+                ..
+            }
+        ));
+        assert!(matches!(
+            gestures[1],
+            TouchGesture::Pan {
+                phase: GesturePhase::Ended,
+                ..
+            }
+        ));
+        assert!(matches!(
+            gestures[2],
+            TouchGesture::Swipe {
+                direction: SwipeDirection::Right,
+                ..
+            }
+        ));
+        assert_eq!(gestures.len(), 3);
+    }
+
+    #[test]
+    fn pinch_rotation_across_pi() {
+        let mut recognizer = GestureRecognizer::new();
+        let gestures = feed_all(
+            &mut recognizer,
+            &[
+                finger(0, FingerEventKind::Down, 1, 0.5, 0.5),
+                finger(10, FingerEventKind::Down, 2, 0.3, 0.51),
+                finger(20, FingerEventKind::Motion, 2, 0.3, 0.49),
+            ],
+        );
+        assert!(matches!(
+            gestures[0],
+            TouchGesture::PinchRotate {
+                phase: GesturePhase::Began,
+                ..
+            }
+        ));
+        match gestures[1] {
+            TouchGesture::PinchRotate {
+                phase: GesturePhase::Changed,
+                scale,
+                rotation,
+                ..
+            } => {
+                assert!((scale - 1.0).abs() < 1e-3);
+                assert!(0.09 < rotation && rotation < 0.11, "rotation: {}", rotation);
+            }
+            ref other => panic!("unexpected gesture: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn same_finger_ids_on_two_devices() {
+        let mut recognizer = GestureRecognizer::new();
+        let gestures = feed_all(
+            &mut recognizer,
+            &[
+                finger_on(1, 0, FingerEventKind::Down, 0, 0.2, 0.5),
+                finger_on(2, 10, FingerEventKind::Down, 0, 0.8, 0.5),
+            ],
+        );
+        assert_eq!(recognizer.fingers.len(), 2);
+        assert!(matches!(
+            gestures[..],
+            [TouchGesture::PinchRotate {
+                phase: GesturePhase::Began,
+                ..
+            }]
+        ));
+        let gestures = feed_all(
+            &mut recognizer,
+            &[
+                finger_on(2, 20, FingerEventKind::Motion, 0, 0.9, 0.5),
+                finger_on(1, 30, FingerEventKind::Up, 0, 0.2, 0.5),
+            ],
+        );
+        assert_eq!(recognizer.fingers.len(), 1);
+        match gestures[..] {
+            [TouchGesture::PinchRotate {
+                phase: GesturePhase::Changed,
+                scale,
+                ..
+            }, TouchGesture::PinchRotate {
+                phase: GesturePhase::Ended,
+                ..
+            }] => assert!((scale - 7.0 / 6.0).abs() < 1e-3, "scale: {}", scale),
+            ref other => panic!("unexpected gestures: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn rotation_is_wrapped() {
+        use std::f32::consts::PI;
+        assert!((rotation_between(PI - 0.1, -PI + 0.1) - 0.2).abs() < 1e-5);
+        assert!((rotation_between(-PI + 0.1, PI - 0.1) + 0.2).abs() < 1e-5);
+        assert!((rotation_between(0.0, 0.5) - 0.5).abs() < 1e-6);
+    }
+}
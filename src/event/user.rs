@@ -0,0 +1,181 @@
+//! Custom events carrying Rust values, pushed from any thread into the event queue.
+
+use std::{any::Any, ffi::c_void, marker::PhantomData};
+
+use crate::{bind, Result, Sdl, SdlError};
+
+use super::EventBox;
+
+/// A marker placed in `data2` of the raw user event, to distinguish the events pushed by [`UserEventSender`] from foreign ones.
+static USER_EVENT_MARKER: u8 = 0;
+
+fn marker_ptr() -> *mut c_void {
+    (&USER_EVENT_MARKER as *const u8 as *mut u8).cast()
+}
+
+type Payload = Box<dyn Any + Send>;
+
+/// A sender to push values of `T` into the event queue as a user event. It can be cloned and sent to other threads.
+pub struct UserEventSender<T> {
+    event_type: u32,
+    _phantom: PhantomData<fn(T)>,
+}
+
+impl<T> std::fmt::Debug for UserEventSender<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UserEventSender")
+            .field("event_type", &self.event_type)
+            .finish()
+    }
+}
+
+impl<T> Clone for UserEventSender<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for UserEventSender<T> {}
+
+impl<T: Send + 'static> UserEventSender<T> {
+    /// Registers a new user event type and constructs the sender of it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if there are no more user event types to register.
+    pub fn new(_: &EventBox) -> Result<Self> {
+        let event_type = unsafe { bind::SDL_RegisterEvents(1) };
+        if event_type == u32::MAX {
+            return Err(SdlError::Others {
+                msg: "no more user event types are available".into(),
+            });
+        }
+        Ok(Self {
+            event_type,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Returns the registered event type.
+    #[must_use]
+    pub fn event_type(&self) -> u32 {
+        self.event_type
+    }
+
+    /// Pushes the value into the event queue. The value will be dropped if it could not be pushed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the event was filtered, or the event queue is full.
+    pub fn push(&self, value: T) -> Result<()> {
+        let payload: *mut Payload = Box::into_raw(Box::new(Box::new(value) as Payload));
+        let mut raw = bind::SDL_Event {
+            user: bind::SDL_UserEvent {
+                type_: self.event_type,
+                timestamp: 0,
+                windowID: 0,
+                code: 0,
+                data1: payload.cast(),
+                data2: marker_ptr(),
+            },
+        };
+        let ret = unsafe { bind::SDL_PushEvent(&mut raw) };
+        if ret == 1 {
+            return Ok(());
+        }
+        let _ = unsafe { Box::from_raw(payload) };
+        if ret == 0 {
+            Err(SdlError::Others {
+                msg: "the user event was filtered".into(),
+            })
+        } else {
+            Err(SdlError::Others { msg: Sdl::error() })
+        }
+    }
+}
+
+/// An event pushed by [`UserEventSender`], owning the sent value.
+pub struct UserEvent {
+    /// When this event occurred.
+    pub timestamp: u32,
+    event_type: u32,
+    payload: Payload,
+}
+
+impl std::fmt::Debug for UserEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UserEvent")
+            .field("timestamp", &self.timestamp)
+            .field("event_type", &self.event_type)
+            .finish_non_exhaustive()
+    }
+}
+
+impl UserEvent {
+    /// Takes the value from the raw event if it was pushed by [`UserEventSender`].
+    ///
+    /// # Safety
+    ///
+    /// The raw event must be removed from the event queue, and must not be decoded twice. Otherwise the value will be freed twice.
+    pub(super) unsafe fn from_raw(raw: bind::SDL_UserEvent) -> Option<Self> {
+        if raw.data2 != marker_ptr() || raw.data1.is_null() {
+            return None;
+        }
+        let payload = *Box::from_raw(raw.data1.cast::<Payload>());
+        Some(Self {
+            timestamp: raw.timestamp,
+            event_type: raw.type_,
+            payload,
+        })
+    }
+
+    /// Drops the value in the raw event if it was pushed by [`UserEventSender`], for the event discarded without decoding.
+    ///
+    /// # Safety
+    ///
+    /// The raw event must be removed from the event queue, and must not be decoded after this.
+    pub(super) unsafe fn free_raw(raw: &bind::SDL_UserEvent) {
+        if raw.data2 == marker_ptr() && !raw.data1.is_null() {
+            let _ = Box::from_raw(raw.data1.cast::<Payload>());
+        }
+    }
+
+    /// Returns the registered event type.
+    #[must_use]
+    pub fn event_type(&self) -> u32 {
+        self.event_type
+    }
+
+    /// Returns whether the event was sent by `sender`.
+    #[must_use]
+    pub fn is_sent_by<T>(&self, sender: &UserEventSender<T>) -> bool {
+        self.event_type == sender.event_type
+    }
+
+    /// Returns the reference to the sent value if it is `T`.
+    #[must_use]
+    pub fn downcast_ref<T: 'static>(&self) -> Option<&T> {
+        self.payload.downcast_ref()
+    }
+
+    /// Takes the sent value if it is `T`, or returns back the event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the sent value is not `T`.
+    pub fn downcast<T: 'static>(self) -> std::result::Result<T, Self> {
+        let Self {
+            timestamp,
+            event_type,
+            payload,
+        } = self;
+        payload
+            .downcast()
+            .map(|value| *value)
+            .map_err(|payload| Self {
+                timestamp,
+                event_type,
+                payload,
+            })
+    }
+}
@@ -37,6 +37,8 @@ pub enum WindowEventDetails {
     FocusLost,
     /// The window was closed.
     Close,
+    /// The kind of the event is unknown, sent by the newer SDL runtime.
+    Unknown(u8),
 }
 
 /// An event on interacting to the window.
@@ -87,7 +89,7 @@ impl From<bind::SDL_WindowEvent> for WindowEvent {
                 bind::SDL_WINDOWEVENT_FOCUS_GAINED => WindowEventDetails::FocusGained,
                 bind::SDL_WINDOWEVENT_FOCUS_LOST => WindowEventDetails::FocusLost,
                 bind::SDL_WINDOWEVENT_CLOSE => WindowEventDetails::Close,
-                _ => todo!(),
+                _ => WindowEventDetails::Unknown(event),
             },
         }
     }
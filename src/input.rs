@@ -0,0 +1,422 @@
+//! An action mapping layer, binding named actions and axes to the inputs from the keyboard, mouse, game controllers and joysticks.
+//!
+//! Feed the events into [`ActionMap::handle_event`], and call [`ActionMap::update`] once a frame, then query the states of the actions and axes.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::event::{
+    game_controller::{axis::Axis, button::Button, event::ControllerEvent},
+    joystick::{event::JoystickEvent, hat::PovHat},
+    keyboard::KeyboardEvent,
+    mouse::MouseEvent,
+    Event,
+};
+
+mod binding;
+
+pub use binding::*;
+
+/// The bound inputs and the dead zone of an axis.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct AxisBinding {
+    inputs: Vec<AxisInput>,
+    dead_zone: f32,
+}
+
+/// A map from named actions and axes to the inputs. The text representation of this can be used to save and load the bindings.
+///
+/// The text representation is made of lines, `action <name> <input>`, `axis <name> <input>` or `dead_zone <name> <value>`. The lines starting with `#` are ignored.
+#[derive(Debug, Clone, Default)]
+pub struct ActionMap {
+    actions: BTreeMap<String, Vec<ActionInput>>,
+    axes: BTreeMap<String, AxisBinding>,
+    held_inputs: HashSet<ActionInput>,
+    pressed_inputs: HashSet<ActionInput>,
+    held_buttons: HashSet<(u32, Button)>,
+    held_hats: HashMap<(u32, u32), PovHat>,
+    controller_axes: HashMap<(u32, Axis), f32>,
+    pressed: HashSet<String>,
+    previous: HashSet<String>,
+    axis_values: HashMap<String, f32>,
+}
+
+impl ActionMap {
+    /// Constructs an empty action map.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Binds the input to the action.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the name is empty, contains whitespaces or starts with `#`, or the key has no name, because it cannot be saved as text.
+    pub fn bind_action(&mut self, name: &str, input: ActionInput) -> Result<(), BindError> {
+        validate_name(name)?;
+        input.validate()?;
+        let inputs = self.actions.entry(name.into()).or_default();
+        if !inputs.contains(&input) {
+            inputs.push(input);
+        }
+        Ok(())
+    }
+
+    /// Binds the input to the axis.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the name is empty, contains whitespaces or starts with `#`, or the key has no name, because it cannot be saved as text.
+    pub fn bind_axis(&mut self, name: &str, input: AxisInput) -> Result<(), BindError> {
+        validate_name(name)?;
+        input.validate()?;
+        let binding = self.axes.entry(name.into()).or_default();
+        if !binding.inputs.contains(&input) {
+            binding.inputs.push(input);
+        }
+        Ok(())
+    }
+
+    /// Sets the dead zone of the axis, the absolute value under this is treated as zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the name is empty, contains whitespaces or starts with `#`, or `dead_zone` is not in `0.0..1.0`.
+    pub fn set_dead_zone(&mut self, name: &str, dead_zone: f32) -> Result<(), BindError> {
+        validate_name(name)?;
+        validate_dead_zone(dead_zone)?;
+        self.axes.entry(name.into()).or_default().dead_zone = dead_zone;
+        Ok(())
+    }
+
+    /// Removes all the bindings of the action.
+    pub fn unbind_action(&mut self, name: &str) {
+        self.actions.remove(name);
+    }
+
+    /// Removes all the bindings of the axis.
+    pub fn unbind_axis(&mut self, name: &str) {
+        self.axes.remove(name);
+    }
+
+    /// Returns the inputs bound to the action.
+    #[must_use]
+    pub fn action_bindings(&self, name: &str) -> &[ActionInput] {
+        self.actions.get(name).map_or(&[], |inputs| inputs)
+    }
+
+    /// Returns the inputs bound to the axis.
+    #[must_use]
+    pub fn axis_bindings(&self, name: &str) -> &[AxisInput] {
+        self.axes.get(name).map_or(&[], |binding| &binding.inputs)
+    }
+
+    fn press(&mut self, input: ActionInput) {
+        self.held_inputs.insert(input);
+        self.pressed_inputs.insert(input);
+    }
+
+    /// Updates the state of the inputs by the event.
+    pub fn handle_event(&mut self, event: &Event) {
+        match event {
+            Event::Keyboard(KeyboardEvent {
+                is_pressed,
+                is_repeated: false,
+                symbol,
+                ..
+            }) => {
+                let input = ActionInput::Key(symbol.key_code);
+                if *is_pressed {
+                    self.press(input);
+                } else {
+                    self.held_inputs.remove(&input);
+                }
+            }
+            Event::Mouse(MouseEvent::Button(button)) => {
+                if let Some(mouse) = button.button {
+                    let input = ActionInput::Mouse(mouse);
+                    if button.is_pressed {
+                        self.press(input);
+                    } else {
+                        self.held_inputs.remove(&input);
+                    }
+                }
+            }
+            Event::Controller(ControllerEvent::Button {
+                id,
+                button,
+                is_pressed,
+                ..
+            }) => {
+                if *is_pressed {
+                    self.held_buttons.insert((id.id, *button));
+                    self.pressed_inputs.insert(ActionInput::Button(*button));
+                } else {
+                    self.held_buttons.remove(&(id.id, *button));
+                }
+            }
+            Event::Controller(ControllerEvent::Axis {
+                id, axis, value, ..
+            }) => {
+                self.controller_axes.insert(
+                    (id.id, *axis),
+                    (f32::from(*value) / 32767.0).clamp(-1.0, 1.0),
+                );
+            }
+            Event::Controller(ControllerEvent::DeviceRemoved { id, .. }) => {
+                self.held_buttons
+                    .retain(|&(controller, _)| controller != id.id);
+                self.controller_axes
+                    .retain(|&(controller, _), _| controller != id.id);
+            }
+            Event::Joystick(JoystickEvent::Hat { id, hat, value, .. }) => {
+                let index = hat.index();
+                let previous = self
+                    .held_hats
+                    .insert((id.id, index), *value)
+                    .unwrap_or(PovHat::CENTERED);
+                for direction in [PovHat::UP, PovHat::RIGHT, PovHat::DOWN, PovHat::LEFT] {
+                    if value.contains(direction) && !previous.contains(direction) {
+                        self.pressed_inputs
+                            .insert(ActionInput::Hat { index, direction });
+                    }
+                }
+            }
+            Event::Joystick(JoystickEvent::DeviceRemoved { id, .. }) => {
+                self.held_hats.retain(|&(joystick, _), _| joystick != id.id);
+            }
+            _ => {}
+        }
+    }
+
+    fn is_held(&self, input: &ActionInput) -> bool {
+        match *input {
+            ActionInput::Button(button) => {
+                self.held_buttons.iter().any(|&(_, held)| held == button)
+            }
+            ActionInput::Hat { index, direction } => self
+                .held_hats
+                .iter()
+                .any(|(&(_, hat), value)| hat == index && value.contains(direction)),
+            _ => self.held_inputs.contains(input),
+        }
+    }
+
+    fn axis_input_value(&self, input: &AxisInput) -> f32 {
+        match input {
+            AxisInput::Axis(axis) => self
+                .controller_axes
+                .iter()
+                .filter(|(&(_, controller_axis), _)| controller_axis == *axis)
+                .map(|(_, &value)| value)
+                .fold(
+                    0.0,
+                    |acc, value| {
+                        if acc.abs() < value.abs() {
+                            value
+                        } else {
+                            acc
+                        }
+                    },
+                ),
+            AxisInput::NegativeKey(key) => {
+                if self.held_inputs.contains(&ActionInput::Key(*key)) {
+                    -1.0
+                } else {
+                    0.0
+                }
+            }
+            AxisInput::PositiveKey(key) => {
+                if self.held_inputs.contains(&ActionInput::Key(*key)) {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+
+    /// Updates the states of the actions and axes for the new frame. Call this once a frame after handling the events.
+    pub fn update(&mut self) {
+        let pressed = self
+            .actions
+            .iter()
+            .filter(|(_, inputs)| {
+                inputs
+                    .iter()
+                    .any(|input| self.is_held(input) || self.pressed_inputs.contains(input))
+            })
+            .map(|(name, _)| name.clone())
+            .collect();
+        self.previous = std::mem::replace(&mut self.pressed, pressed);
+        self.pressed_inputs.clear();
+
+        let axis_values = self
+            .axes
+            .iter()
+            .map(|(name, binding)| {
+                let sum: f32 = binding
+                    .inputs
+                    .iter()
+                    .map(|input| {
+                        let value = self.axis_input_value(input);
+                        if value.abs() <= binding.dead_zone {
+                            0.0
+                        } else {
+                            value.signum() * (value.abs() - binding.dead_zone)
+                                / (1.0 - binding.dead_zone)
+                        }
+                    })
+                    .sum();
+                (name.clone(), sum.clamp(-1.0, 1.0))
+            })
+            .collect();
+        self.axis_values = axis_values;
+    }
+
+    /// Returns whether the action is pressed in the current frame.
+    #[must_use]
+    pub fn is_pressed(&self, name: &str) -> bool {
+        self.pressed.contains(name)
+    }
+
+    /// Returns whether the action was pressed newly in the current frame.
+    #[must_use]
+    pub fn is_just_pressed(&self, name: &str) -> bool {
+        self.pressed.contains(name) && !self.previous.contains(name)
+    }
+
+    /// Returns whether the action was released newly in the current frame.
+    #[must_use]
+    pub fn is_just_released(&self, name: &str) -> bool {
+        !self.pressed.contains(name) && self.previous.contains(name)
+    }
+
+    /// Returns the value of the axis in the current frame, in `-1.0..=1.0`.
+    #[must_use]
+    pub fn axis(&self, name: &str) -> f32 {
+        self.axis_values.get(name).copied().unwrap_or(0.0)
+    }
+}
+
+impl std::fmt::Display for ActionMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (name, inputs) in &self.actions {
+            for input in inputs {
+                writeln!(f, "action {} {}", name, input)?;
+            }
+        }
+        for (name, binding) in &self.axes {
+            for input in &binding.inputs {
+                writeln!(f, "axis {} {}", name, input)?;
+            }
+            if binding.dead_zone != 0.0 {
+                writeln!(f, "dead_zone {} {}", name, binding.dead_zone)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for ActionMap {
+    type Err = ParseBindingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut map = Self::new();
+        for line in s.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let err = || ParseBindingError::new(line);
+            let mut fields = line.splitn(3, ' ');
+            let (kind, name, rest) = match (fields.next(), fields.next(), fields.next()) {
+                (Some(kind), Some(name), Some(rest)) => (kind, name, rest),
+                _ => return Err(err()),
+            };
+            match kind {
+                "action" => map.bind_action(name, rest.parse()?)?,
+                "axis" => map.bind_axis(name, rest.parse()?)?,
+                "dead_zone" => {
+                    let dead_zone: f32 = rest.trim().parse().map_err(|_| err())?;
+                    map.set_dead_zone(name, dead_zone)?;
+                }
+                _ => return Err(err()),
+            }
+        }
+        Ok(map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::{
+        game_controller::button::FourButton, keyboard::key_code::KeyCode, mouse::MouseButton,
+    };
+
+    #[test]
+    fn action_map_round_trip() {
+        let mut map = ActionMap::new();
+        map.bind_action("jump", ActionInput::Key(KeyCode::Space))
+            .unwrap();
+        map.bind_action("jump", ActionInput::Mouse(MouseButton::Left))
+            .unwrap();
+        map.bind_action(
+            "jump",
+            ActionInput::Button(Button::RightFour(FourButton::Down)),
+        )
+        .unwrap();
+        map.bind_action(
+            "menu",
+            ActionInput::Button(Button::LeftFour(FourButton::Up)),
+        )
+        .unwrap();
+        map.bind_action("menu", ActionInput::Button(Button::RightShoulder))
+            .unwrap();
+        map.bind_action(
+            "up",
+            ActionInput::Hat {
+                index: 0,
+                direction: PovHat::UP,
+            },
+        )
+        .unwrap();
+        map.bind_axis("move_x", AxisInput::Axis(Axis::LeftX))
+            .unwrap();
+        map.bind_axis("move_x", AxisInput::NegativeKey(KeyCode::A))
+            .unwrap();
+        map.set_dead_zone("move_x", 0.25).unwrap();
+
+        let text = map.to_string();
+        let parsed: ActionMap = text.parse().unwrap();
+        assert_eq!(parsed.actions, map.actions);
+        assert_eq!(parsed.axes, map.axes);
+        assert_eq!(parsed.to_string(), text);
+        assert!(text.contains("action jump button a\n"));
+        assert!(text.contains("action menu button dpup\n"));
+        assert!(text.contains("action menu button rightshoulder\n"));
+    }
+
+    #[test]
+    fn invalid_names_are_rejected() {
+        let mut map = ActionMap::new();
+        for name in ["", "two words", "#comment"] {
+            assert_eq!(
+                map.bind_action(name, ActionInput::Mouse(MouseButton::Left)),
+                Err(BindError::InvalidName(name.into()))
+            );
+        }
+        assert_eq!(
+            map.bind_action("unknown", ActionInput::Key(KeyCode::Unknown)),
+            Err(BindError::UnnamedKey(KeyCode::Unknown))
+        );
+        for dead_zone in [-0.5, 1.0, f32::NAN] {
+            assert_eq!(
+                map.set_dead_zone("move_x", dead_zone)
+                    .map_err(|err| err.to_string()),
+                Err(BindError::InvalidDeadZone(dead_zone).to_string())
+            );
+        }
+        assert!(map.to_string().is_empty());
+    }
+}
@@ -0,0 +1,224 @@
+//! Inputs to be bound to actions and axes.
+
+use std::str::FromStr;
+
+use crate::event::{
+    game_controller::{axis::Axis, button::Button},
+    joystick::hat::PovHat,
+    keyboard::key_code::KeyCode,
+    mouse::MouseButton,
+};
+
+/// An error to tell the failure on parsing the bindings from string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseBindingError {
+    text: String,
+}
+
+impl ParseBindingError {
+    pub(super) fn new(text: &str) -> Self {
+        Self { text: text.into() }
+    }
+}
+
+impl std::fmt::Display for ParseBindingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid binding: {}", self.text)
+    }
+}
+
+impl std::error::Error for ParseBindingError {}
+
+impl From<BindError> for ParseBindingError {
+    fn from(err: BindError) -> Self {
+        Self {
+            text: err.to_string(),
+        }
+    }
+}
+
+/// An error to tell the binding cannot be represented in the text representation of [`super::ActionMap`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum BindError {
+    /// The name of the action or axis was empty, contained whitespaces or started with `#`.
+    InvalidName(String),
+    /// The key has no name to be saved as text.
+    UnnamedKey(KeyCode),
+    /// The dead zone was not in `0.0..1.0`.
+    InvalidDeadZone(f32),
+}
+
+impl std::fmt::Display for BindError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BindError::InvalidName(name) => write!(f, "invalid name: {:?}", name),
+            BindError::UnnamedKey(key) => write!(f, "unnamed key: {:?}", key),
+            BindError::InvalidDeadZone(dead_zone) => write!(f, "invalid dead zone: {}", dead_zone),
+        }
+    }
+}
+
+impl std::error::Error for BindError {}
+
+pub(super) fn validate_name(name: &str) -> Result<(), BindError> {
+    if name.is_empty() || name.starts_with('#') || name.contains(char::is_whitespace) {
+        return Err(BindError::InvalidName(name.into()));
+    }
+    Ok(())
+}
+
+pub(super) fn validate_dead_zone(dead_zone: f32) -> Result<(), BindError> {
+    if !(0.0..1.0).contains(&dead_zone) {
+        return Err(BindError::InvalidDeadZone(dead_zone));
+    }
+    Ok(())
+}
+
+fn validate_key(key: KeyCode) -> Result<(), BindError> {
+    if key.name().is_none() {
+        return Err(BindError::UnnamedKey(key));
+    }
+    Ok(())
+}
+
+/// An input to be bound to an action, which is pressed or released.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ActionInput {
+    /// A key on the keyboard.
+    Key(KeyCode),
+    /// A button on the mouse.
+    Mouse(MouseButton),
+    /// A button on a game controller.
+    Button(Button),
+    /// A direction of a hat on any joystick.
+    Hat {
+        /// The index of the hat.
+        index: u32,
+        /// The direction of the hat, it should be one of up, right, down and left.
+        direction: PovHat,
+    },
+}
+
+fn mouse_button_name(button: MouseButton) -> &'static str {
+    match button {
+        MouseButton::Left => "left",
+        MouseButton::Middle => "middle",
+        MouseButton::Right => "right",
+        MouseButton::X1 => "x1",
+        MouseButton::X2 => "x2",
+    }
+}
+
+fn hat_direction_name(direction: PovHat) -> &'static str {
+    match direction {
+        PovHat::UP => "up",
+        PovHat::RIGHT => "right",
+        PovHat::DOWN => "down",
+        PovHat::LEFT => "left",
+        _ => "centered",
+    }
+}
+
+impl ActionInput {
+    pub(super) fn validate(&self) -> Result<(), BindError> {
+        match *self {
+            ActionInput::Key(key) => validate_key(key),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl std::fmt::Display for ActionInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ActionInput::Key(key) => write!(f, "key {}", key),
+            ActionInput::Mouse(button) => write!(f, "mouse {}", mouse_button_name(*button)),
+            ActionInput::Button(button) => write!(f, "button {}", button),
+            ActionInput::Hat { index, direction } => {
+                write!(f, "hat {} {}", index, hat_direction_name(*direction))
+            }
+        }
+    }
+}
+
+impl FromStr for ActionInput {
+    type Err = ParseBindingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseBindingError::new(s);
+        let (kind, rest) = s.trim().split_once(' ').ok_or_else(err)?;
+        let rest = rest.trim();
+        Ok(match kind {
+            "key" => ActionInput::Key(rest.parse().map_err(|_| err())?),
+            "mouse" => ActionInput::Mouse(match rest {
+                "left" => MouseButton::Left,
+                "middle" => MouseButton::Middle,
+                "right" => MouseButton::Right,
+                "x1" => MouseButton::X1,
+                "x2" => MouseButton::X2,
+                _ => return Err(err()),
+            }),
+            "button" => ActionInput::Button(rest.parse().map_err(|_| err())?),
+            "hat" => {
+                let (index, direction) = rest.split_once(' ').ok_or_else(err)?;
+                ActionInput::Hat {
+                    index: index.parse().map_err(|_| err())?,
+                    direction: match direction.trim() {
+                        "up" => PovHat::UP,
+                        "right" => PovHat::RIGHT,
+                        "down" => PovHat::DOWN,
+                        "left" => PovHat::LEFT,
+                        _ => return Err(err()),
+                    },
+                }
+            }
+            _ => return Err(err()),
+        })
+    }
+}
+
+/// An input to be bound to an axis, which has an analog value in `-1.0..=1.0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AxisInput {
+    /// An axis on a game controller.
+    Axis(Axis),
+    /// A key on the keyboard to make the value `-1.0` while pressed.
+    NegativeKey(KeyCode),
+    /// A key on the keyboard to make the value `1.0` while pressed.
+    PositiveKey(KeyCode),
+}
+
+impl AxisInput {
+    pub(super) fn validate(&self) -> Result<(), BindError> {
+        match *self {
+            AxisInput::NegativeKey(key) | AxisInput::PositiveKey(key) => validate_key(key),
+            AxisInput::Axis(_) => Ok(()),
+        }
+    }
+}
+
+impl std::fmt::Display for AxisInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AxisInput::Axis(axis) => write!(f, "axis {}", axis),
+            AxisInput::NegativeKey(key) => write!(f, "negative_key {}", key),
+            AxisInput::PositiveKey(key) => write!(f, "positive_key {}", key),
+        }
+    }
+}
+
+impl FromStr for AxisInput {
+    type Err = ParseBindingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseBindingError::new(s);
+        let (kind, rest) = s.trim().split_once(' ').ok_or_else(err)?;
+        let rest = rest.trim();
+        Ok(match kind {
+            "axis" => AxisInput::Axis(rest.parse().map_err(|_| err())?),
+            "negative_key" => AxisInput::NegativeKey(rest.parse().map_err(|_| err())?),
+            "positive_key" => AxisInput::PositiveKey(rest.parse().map_err(|_| err())?),
+            _ => return Err(err()),
+        })
+    }
+}
@@ -18,6 +18,7 @@
 //! ## Crate features
 //!
 //! - `vulkan`: The Vulkan support API wrapper.
+//! - `primary_selection`: The primary selection in [`clipboard`], which requires linking SDL 2.26.0 or later.
 //! - `nightly`: The features can be used on nightly.
 //!   - `simd_allocator`: The wrapper of SIMD-friendly allocator.
 
@@ -27,6 +28,8 @@ pub mod event;
 pub mod file;
 pub mod haptic;
 pub mod hint;
+pub mod input;
+pub mod locale;
 #[cfg(feature = "mixer")]
 pub mod mixer;
 pub mod power;
@@ -0,0 +1,54 @@
+//! Querying the locales preferred by the user.
+
+use std::ffi::CStr;
+
+use crate::bind;
+
+/// A locale, which is a pair of the language and the optional country.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Locale {
+    /// The language code in ISO-639, such as `"en"`.
+    pub language: String,
+    /// The country code in ISO-3166 if specified, such as `"US"`.
+    pub country: Option<String>,
+}
+
+impl std::fmt::Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.country {
+            Some(country) => write!(f, "{}_{}", self.language, country),
+            None => write!(f, "{}", self.language),
+        }
+    }
+}
+
+/// Returns the locales preferred by the user, in the order of preference. It may be empty if unavailable.
+///
+/// The result may change while the program is running, then [`crate::app::AppEvent::LocaleChanged`] will occur.
+#[must_use]
+pub fn preferred_locales() -> Vec<Locale> {
+    let ptr = unsafe { bind::SDL_GetPreferredLocales() };
+    if ptr.is_null() {
+        return vec![];
+    }
+    let mut locales = vec![];
+    let mut cursor = ptr;
+    loop {
+        let raw = unsafe { &*cursor };
+        if raw.language.is_null() {
+            break;
+        }
+        let language = unsafe { CStr::from_ptr(raw.language) }
+            .to_string_lossy()
+            .into();
+        let country = (!raw.country.is_null()).then(|| {
+            unsafe { CStr::from_ptr(raw.country) }
+                .to_string_lossy()
+                .into()
+        });
+        locales.push(Locale { language, country });
+        cursor = unsafe { cursor.add(1) };
+    }
+    unsafe { bind::SDL_free(ptr.cast()) }
+    locales
+}
@@ -1,8 +1,40 @@
 //! Clipboard controls.
+//!
+//! The changes of the clipboard are notified by [`ClipboardUpdateEvent`], which can be handled by [`crate::EventBox::handle_clipboard_update`].
+//!
+//! The primary selection on X11 and Wayland is available with `primary_selection` feature, which requires linking SDL 2.26.0 or later.
 
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
 
-use crate::bind;
+use crate::{bind, Result, Sdl, SdlError};
+
+#[cfg(feature = "primary_selection")]
+mod primary {
+    //! The functions of the primary selection, declared here because the bindings are generated from SDL 2.0.22.
+    #![allow(non_snake_case)]
+
+    use std::os::raw::{c_char, c_int};
+
+    use crate::{bind, Sdl};
+
+    extern "C" {
+        pub fn SDL_GetPrimarySelectionText() -> *mut c_char;
+        pub fn SDL_SetPrimarySelectionText(text: *const c_char) -> c_int;
+        pub fn SDL_HasPrimarySelectionText() -> bind::SDL_bool;
+    }
+
+    /// Returns whether the running SDL2 has the primary selection, added in SDL 2.26.0.
+    pub fn is_supported() -> bool {
+        let version = Sdl::version();
+        (2, 26) <= (version.major, version.minor)
+    }
+}
+
+fn to_c_string(text: &str) -> Result<CString> {
+    CString::new(text).map_err(|_| SdlError::Others {
+        msg: "text must not contain a null character".into(),
+    })
+}
 
 /// A text from the clipboard.
 #[derive(Debug, PartialEq, Eq)]
@@ -18,9 +50,38 @@ impl ClipboardText {
         if ptr.is_null() {
             return None;
         }
-        let text = unsafe { CString::from_raw(ptr) }.into_string().ok()?;
+        let text = unsafe { CStr::from_ptr(ptr) }.to_str().ok().map(Into::into);
         unsafe { bind::SDL_free(ptr.cast()) }
-        Some(Self { text })
+        Some(Self { text: text? })
+    }
+
+    /// Sets the text into the clipboard.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if `text` contains a null character, or failed to set the clipboard.
+    pub fn set(text: &str) -> Result<()> {
+        let cstr = to_c_string(text)?;
+        let ret = unsafe { bind::SDL_SetClipboardText(cstr.as_ptr()) };
+        if ret != 0 {
+            return Err(SdlError::Others { msg: Sdl::error() });
+        }
+        Ok(())
+    }
+
+    /// Clears the text in the clipboard.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if failed to clear the clipboard.
+    pub fn clear() -> Result<()> {
+        Self::set("")
+    }
+
+    /// Returns whether the clipboard has a non-empty text.
+    #[must_use]
+    pub fn has_text() -> bool {
+        unsafe { bind::SDL_HasClipboardText() == bind::SDL_TRUE }
     }
 
     /// Returns a reference to the clipboard string .
@@ -35,3 +96,60 @@ impl ClipboardText {
         self.text
     }
 }
+
+#[cfg(feature = "primary_selection")]
+impl ClipboardText {
+    /// Get a text of the primary selection if exists. It is always `None` on SDL2 older than 2.26.0.
+    #[must_use]
+    pub fn primary_selection() -> Option<Self> {
+        if !primary::is_supported() {
+            return None;
+        }
+        let ptr = unsafe { primary::SDL_GetPrimarySelectionText() };
+        if ptr.is_null() {
+            return None;
+        }
+        let text = unsafe { CStr::from_ptr(ptr) }.to_str().ok().map(Into::into);
+        unsafe { bind::SDL_free(ptr.cast()) }
+        Some(Self { text: text? })
+    }
+
+    /// Sets the text into the primary selection.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(UnsupportedFeature)` on SDL2 older than 2.26.0, or `Err(Others)` if `text` contains a null character or failed to set the primary selection.
+    pub fn set_primary_selection(text: &str) -> Result<()> {
+        if !primary::is_supported() {
+            return Err(SdlError::UnsupportedFeature);
+        }
+        let cstr = to_c_string(text)?;
+        let ret = unsafe { primary::SDL_SetPrimarySelectionText(cstr.as_ptr()) };
+        if ret != 0 {
+            return Err(SdlError::Others { msg: Sdl::error() });
+        }
+        Ok(())
+    }
+
+    /// Returns whether the primary selection has a non-empty text. It is always `false` on SDL2 older than 2.26.0.
+    #[must_use]
+    pub fn has_primary_selection() -> bool {
+        primary::is_supported()
+            && unsafe { primary::SDL_HasPrimarySelectionText() == bind::SDL_TRUE }
+    }
+}
+
+/// An event on the clipboard content was updated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClipboardUpdateEvent {
+    /// When this event occurred.
+    pub timestamp: u32,
+}
+
+impl From<bind::SDL_CommonEvent> for ClipboardUpdateEvent {
+    fn from(raw: bind::SDL_CommonEvent) -> Self {
+        Self {
+            timestamp: raw.timestamp,
+        }
+    }
+}
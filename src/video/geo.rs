@@ -1,9 +1,11 @@
-//! Geometry structures such as [`Point`], [`Size`], [`Rect`] and so on.
+//! Geometry structures such as [`Point`], [`FPoint`], [`Size`], [`Rect`] and so on.
 
 use crate::bind;
 
+mod frect;
 mod rect;
 
+pub use frect::*;
 pub use rect::*;
 
 /// A point of the cartesian coordinate system.
@@ -55,6 +57,55 @@ impl Point {
     }
 }
 
+/// A point of the cartesian coordinate system in floating point.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[must_use]
+pub struct FPoint {
+    /// An x coordinate of the point.
+    pub x: f32,
+    /// An y coordinate of the point.
+    pub y: f32,
+}
+
+impl From<bind::SDL_FPoint> for FPoint {
+    fn from(bind::SDL_FPoint { x, y }: bind::SDL_FPoint) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<FPoint> for bind::SDL_FPoint {
+    fn from(FPoint { x, y }: FPoint) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<Point> for FPoint {
+    fn from(Point { x, y }: Point) -> Self {
+        Self {
+            x: x as f32,
+            y: y as f32,
+        }
+    }
+}
+
+impl FPoint {
+    /// Calculates the new point with the offset.
+    pub fn offset(self, x: f32, y: f32) -> Self {
+        Self {
+            x: self.x + x,
+            y: self.y + y,
+        }
+    }
+
+    /// Returns the point rounded to the nearest integers.
+    pub fn round(self) -> Point {
+        Point {
+            x: self.x.round() as i32,
+            y: self.y.round() as i32,
+        }
+    }
+}
+
 /// A geometry size representation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[must_use]
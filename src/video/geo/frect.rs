@@ -0,0 +1,93 @@
+use crate::bind;
+
+use super::{FPoint, Rect};
+
+/// A rectangle holding up left point and size in floating point.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[must_use]
+pub struct FRect {
+    /// A up left point of the rectangle.
+    pub up_left: FPoint,
+    /// A width of the rectangle.
+    pub width: f32,
+    /// A height of the rectangle.
+    pub height: f32,
+}
+
+impl From<bind::SDL_FRect> for FRect {
+    fn from(bind::SDL_FRect { x, y, w, h }: bind::SDL_FRect) -> Self {
+        Self {
+            up_left: FPoint { x, y },
+            width: w,
+            height: h,
+        }
+    }
+}
+
+impl From<FRect> for bind::SDL_FRect {
+    fn from(
+        FRect {
+            up_left,
+            width,
+            height,
+        }: FRect,
+    ) -> Self {
+        Self {
+            x: up_left.x,
+            y: up_left.y,
+            w: width,
+            h: height,
+        }
+    }
+}
+
+impl From<Rect> for FRect {
+    fn from(Rect { up_left, size }: Rect) -> Self {
+        Self {
+            up_left: up_left.into(),
+            width: size.width as f32,
+            height: size.height as f32,
+        }
+    }
+}
+
+impl FRect {
+    /// Constructs a rect from the center.
+    pub fn from_center(center: FPoint, width: f32, height: f32) -> Self {
+        Self {
+            up_left: FPoint {
+                x: center.x - width / 2.0,
+                y: center.y - height / 2.0,
+            },
+            width,
+            height,
+        }
+    }
+
+    /// Returns the bottom right point of the rectangle.
+    pub fn bottom_right(self) -> FPoint {
+        FPoint {
+            x: self.up_left.x + self.width,
+            y: self.up_left.y + self.height,
+        }
+    }
+
+    /// Returns the center point of the rectangle.
+    pub fn center(self) -> FPoint {
+        FPoint {
+            x: self.up_left.x + self.width / 2.0,
+            y: self.up_left.y + self.height / 2.0,
+        }
+    }
+
+    /// Returns the rect rounded to the nearest integers.
+    pub fn round(self) -> Rect {
+        Rect {
+            up_left: self.up_left.round(),
+            size: super::Size {
+                width: self.width.round().max(0.0) as u32,
+                height: self.height.round().max(0.0) as u32,
+            },
+        }
+    }
+}
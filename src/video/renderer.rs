@@ -1,6 +1,7 @@
 //! Renderer for a window, to render some geometries or query driver information.
 
 use static_assertions::assert_not_impl_all;
+use std::cell::Cell;
 use std::mem::MaybeUninit;
 use std::ptr::NonNull;
 
@@ -9,23 +10,36 @@ use clip::ClippedRenderer;
 use super::window::Window;
 use crate::{
     as_raw, bind,
+    color::{pixel::kind::PixelFormatKind, Rgb},
     geo::{Rect, Scale, Size},
+    surface::{
+        bmp::{BmpSaveError, BmpSaveExt},
+        owned::Owned,
+        Surface,
+    },
     texture::Texture,
     Result, Sdl, SdlError,
 };
 
+mod builder;
 pub mod clip;
 pub mod driver;
+mod frame;
+mod geometry;
 pub mod info;
 mod paste;
 pub mod pen;
 
+pub use builder::*;
+pub use frame::*;
+pub use geometry::*;
 pub use paste::*;
 
 /// A SDL2 renderer. This is often used for rendering with [`pen::Pen`].
 pub struct Renderer<'window> {
     renderer: NonNull<bind::SDL_Renderer>,
     window: &'window Window<'window>,
+    in_frame: Cell<bool>,
 }
 
 impl std::fmt::Debug for Renderer<'_> {
@@ -39,20 +53,45 @@ impl std::fmt::Debug for Renderer<'_> {
 assert_not_impl_all!(Renderer: Send, Sync);
 
 impl<'window> Renderer<'window> {
-    /// Constructs a renderer from the window.
+    /// Constructs a renderer from the window with the default flags. Use [`RendererBuilder`] to specify the flags.
     #[must_use]
     pub fn new(window: &'window Window) -> Self {
         let raw = unsafe { bind::SDL_CreateRenderer(window.as_ptr(), -1, 0) };
         NonNull::new(raw).map_or_else(
             || Sdl::error_then_panic("Sdl renderer"),
-            |renderer| Self { renderer, window },
+            |renderer| Self::from_raw(renderer, window),
         )
     }
 
+    fn from_raw(renderer: NonNull<bind::SDL_Renderer>, window: &'window Window) -> Self {
+        Self {
+            renderer,
+            window,
+            in_frame: Cell::new(false),
+        }
+    }
+
     pub(crate) fn as_ptr(&self) -> *mut bind::SDL_Renderer {
         self.renderer.as_ptr()
     }
 
+    /// Presents the rendered result to the window.
+    pub fn present(&self) {
+        unsafe { bind::SDL_RenderPresent(self.as_ptr()) }
+    }
+
+    /// Starts a new frame [`Frame`] by clearing with `clear_color`, which presents once on dropped. Returns `None` if another frame of the renderer is alive.
+    #[must_use]
+    pub fn frame(&self, clear_color: Rgb) -> Option<Frame> {
+        Frame::new(self, clear_color)
+    }
+
+    /// Returns whether a [`Frame`] of the renderer is alive.
+    #[must_use]
+    pub fn is_in_frame(&self) -> bool {
+        self.in_frame.get()
+    }
+
     /// Returns the borrowing window.
     #[must_use]
     pub fn window(&self) -> &Window {
@@ -75,6 +114,50 @@ impl<'window> Renderer<'window> {
         }
     }
 
+    /// Reads the pixels in `area` of the rendering target, or whole if `None`, into a new surface in the pixel format `kind`.
+    ///
+    /// This must be called before presenting, because the rendering target is undefined after presenting.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if `kind` is a FourCC format such as YUV, or failed to allocate the surface or to read the pixels.
+    pub fn read_pixels(&self, area: Option<Rect>, kind: PixelFormatKind) -> Result<Owned> {
+        let size = match area {
+            Some(area) => area.size,
+            None => self.output_size()?,
+        };
+        let surface = Owned::with_format(size, kind)?;
+        let area = area.map(Into::into);
+        let ret = unsafe {
+            let raw = surface.as_ptr().as_ptr();
+            bind::SDL_RenderReadPixels(
+                self.as_ptr(),
+                as_raw(&area),
+                kind.as_raw(),
+                (*raw).pixels,
+                (*raw).pitch,
+            )
+        };
+        if ret != 0 {
+            return Err(SdlError::Others { msg: Sdl::error() });
+        }
+        Ok(surface)
+    }
+
+    /// Saves the whole rendering target as BMP format into the file, for taking a screenshot.
+    ///
+    /// This must be called before presenting, because the rendering target is undefined after presenting.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if failed to read the pixels or to save an image to the file.
+    pub fn save_screenshot(&self, file_name: &str) -> Result<()> {
+        let kind = self.window.pixel_format();
+        self.read_pixels(None, kind)?
+            .save_bmp(file_name)
+            .map_err(|BmpSaveError(msg)| SdlError::Others { msg })
+    }
+
     /// Clips the renderer by `area`.
     pub fn clip(&'window mut self, area: Rect) -> ClippedRenderer<'window> {
         ClippedRenderer::new(self, area)
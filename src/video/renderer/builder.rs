@@ -0,0 +1,50 @@
+use std::os::raw::c_int;
+use std::ptr::NonNull;
+use typed_builder::TypedBuilder;
+
+use super::Renderer;
+use crate::{bind, window::Window, Result, Sdl, SdlError};
+
+/// A builder for the [`Renderer`].
+#[derive(Debug, TypedBuilder)]
+pub struct RendererBuilder {
+    /// The index of the rendering driver in [`super::driver::drivers`], or the first one supporting the flags if `None`.
+    #[builder(default, setter(strip_option))]
+    driver_index: Option<u32>,
+    #[builder(default)]
+    software: bool,
+    #[builder(default)]
+    accelerated: bool,
+    #[builder(default)]
+    present_vsync: bool,
+    #[builder(default)]
+    target_texture: bool,
+}
+
+impl RendererBuilder {
+    /// Builds the renderer for the window.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if no driver supports the flags, or failed to create the renderer.
+    pub fn new_renderer<'window>(self, window: &'window Window) -> Result<Renderer<'window>> {
+        let mut flags = 0u32;
+        if self.software {
+            flags |= bind::SDL_RENDERER_SOFTWARE as u32;
+        }
+        if self.accelerated {
+            flags |= bind::SDL_RENDERER_ACCELERATED as u32;
+        }
+        if self.present_vsync {
+            flags |= bind::SDL_RENDERER_PRESENTVSYNC as u32;
+        }
+        if self.target_texture {
+            flags |= bind::SDL_RENDERER_TARGETTEXTURE as u32;
+        }
+        let index = self.driver_index.map_or(-1, |index| index as c_int);
+        let raw = unsafe { bind::SDL_CreateRenderer(window.as_ptr(), index, flags) };
+        NonNull::new(raw)
+            .map(|renderer| Renderer::from_raw(renderer, window))
+            .ok_or_else(|| SdlError::Others { msg: Sdl::error() })
+    }
+}
@@ -0,0 +1,39 @@
+use super::{pen::Pen, Renderer};
+use crate::color::Rgb;
+
+/// A frame of the rendering. Constructing this clears the renderer, and dropping this presents the rendered result once.
+///
+/// Use [`Frame::pen`] to draw. While the frame is alive, [`Pen::new`] neither clears nor presents, so drawing with several pens does not present several times per frame.
+///
+/// Do not leak this by such as [`std::mem::forget`]. Then the renderer is regarded as in the frame forever, so [`Renderer::frame`] always returns `None` and [`Pen::new`] never presents.
+#[derive(Debug)]
+pub struct Frame<'renderer> {
+    renderer: &'renderer Renderer<'renderer>,
+}
+
+impl<'renderer> Frame<'renderer> {
+    /// Starts the frame by clearing the renderer with `clear_color`, or returns `None` if another frame of the renderer is alive.
+    #[must_use]
+    pub fn new(renderer: &'renderer Renderer, clear_color: Rgb) -> Option<Self> {
+        if renderer.in_frame.replace(true) {
+            return None;
+        }
+        let pen = Pen::without_present(renderer);
+        pen.set_color(clear_color);
+        pen.clear();
+        Some(Self { renderer })
+    }
+
+    /// Returns a new pen to draw on this frame, which does not present on dropped.
+    #[must_use]
+    pub fn pen(&self) -> Pen<'renderer> {
+        Pen::without_present(self.renderer)
+    }
+}
+
+impl Drop for Frame<'_> {
+    fn drop(&mut self) {
+        self.renderer.in_frame.set(false);
+        self.renderer.present();
+    }
+}
@@ -0,0 +1,193 @@
+//! Rendering triangles with colored and textured vertices.
+
+use std::ffi::c_void;
+use std::mem::size_of;
+use std::os::raw::c_int;
+
+use super::Renderer;
+use crate::color::Rgba;
+use crate::geo::FPoint;
+use crate::texture::Texture;
+use crate::{bind, Result, Sdl, SdlError};
+
+/// A vertex of the triangles for [`Renderer::geometry`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vertex {
+    /// The position of the vertex on the renderer.
+    pub position: FPoint,
+    /// The color of the vertex, blended with the texture.
+    pub color: Rgba,
+    /// The normalized coordinates on the texture, from `(0.0, 0.0)` to `(1.0, 1.0)`. This is ignored without the texture.
+    pub tex_coord: FPoint,
+}
+
+impl From<Vertex> for bind::SDL_Vertex {
+    fn from(
+        Vertex {
+            position,
+            color,
+            tex_coord,
+        }: Vertex,
+    ) -> Self {
+        Self {
+            position: position.into(),
+            color: color.into(),
+            tex_coord: tex_coord.into(),
+        }
+    }
+}
+
+/// Indices into the vertices for [`Renderer::geometry_raw`], in the size of the index type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeometryIndices<'indices> {
+    /// Indices in 8 bits.
+    U8(&'indices [u8]),
+    /// Indices in 16 bits.
+    U16(&'indices [u16]),
+    /// Indices in 32 bits.
+    U32(&'indices [u32]),
+}
+
+impl GeometryIndices<'_> {
+    fn len(&self) -> usize {
+        match self {
+            GeometryIndices::U8(indices) => indices.len(),
+            GeometryIndices::U16(indices) => indices.len(),
+            GeometryIndices::U32(indices) => indices.len(),
+        }
+    }
+
+    fn max(&self) -> Option<u32> {
+        match self {
+            GeometryIndices::U8(indices) => indices.iter().max().map(|&i| i.into()),
+            GeometryIndices::U16(indices) => indices.iter().max().map(|&i| i.into()),
+            GeometryIndices::U32(indices) => indices.iter().max().copied(),
+        }
+    }
+
+    fn as_raw(&self) -> (*const c_void, c_int) {
+        match self {
+            GeometryIndices::U8(indices) => (indices.as_ptr().cast(), 1),
+            GeometryIndices::U16(indices) => (indices.as_ptr().cast(), 2),
+            GeometryIndices::U32(indices) => (indices.as_ptr().cast(), 4),
+        }
+    }
+}
+
+fn invalid_geometry(msg: String) -> SdlError {
+    SdlError::Others { msg }
+}
+
+fn check_triangles(num_vertices: usize, indices: Option<GeometryIndices>) -> Result<()> {
+    let num_points = indices.map_or(num_vertices, |indices| indices.len());
+    if num_points % 3 != 0 {
+        return Err(invalid_geometry(format!(
+            "the numbers of the vertices or indices {} is not a multiple of 3",
+            num_points
+        )));
+    }
+    if let Some(max) = indices.and_then(|indices| indices.max()) {
+        if num_vertices <= max as usize {
+            return Err(invalid_geometry(format!(
+                "the index {} is out of the vertices",
+                max
+            )));
+        }
+    }
+    if c_int::MAX as usize <= num_vertices.max(num_points) {
+        return Err(invalid_geometry("too many vertices or indices".into()));
+    }
+    Ok(())
+}
+
+impl Renderer<'_> {
+    /// Renders the triangles from `vertices` with the `texture` if exists.
+    ///
+    /// Every three vertices make a triangle, or every three `indices` into `vertices` if exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the numbers of the vertices or indices is not a multiple of 3, an index is out of `vertices`, or failed to render the triangles.
+    pub fn geometry(
+        &self,
+        texture: Option<&Texture>,
+        vertices: &[Vertex],
+        indices: Option<&[u32]>,
+    ) -> Result<()> {
+        check_triangles(vertices.len(), indices.map(GeometryIndices::U32))?;
+        let vertices: Vec<bind::SDL_Vertex> = vertices.iter().map(|&v| v.into()).collect();
+        let (indices_ptr, indices_len) = indices.map_or((std::ptr::null(), 0), |indices| {
+            (indices.as_ptr().cast::<c_int>(), indices.len() as c_int)
+        });
+        let ret = unsafe {
+            bind::SDL_RenderGeometry(
+                self.as_ptr(),
+                texture.map_or(std::ptr::null_mut(), Texture::as_ptr),
+                vertices.as_ptr(),
+                vertices.len() as c_int,
+                indices_ptr,
+                indices_len,
+            )
+        };
+        if ret != 0 {
+            return Err(SdlError::Others { msg: Sdl::error() });
+        }
+        Ok(())
+    }
+
+    /// Renders the triangles from the separated attributes of the vertices with the `texture` if exists. `tex_coords` is required with the texture, and ignored without the texture.
+    ///
+    /// Every three vertices make a triangle, or every three `indices` into the vertices if exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the lengths of the attributes are different, `tex_coords` is missing for the texture, the numbers of the vertices or indices is not a multiple of 3, an index is out of the vertices, or failed to render the triangles.
+    pub fn geometry_raw(
+        &self,
+        texture: Option<&Texture>,
+        positions: &[FPoint],
+        colors: &[Rgba],
+        tex_coords: Option<&[FPoint]>,
+        indices: Option<GeometryIndices>,
+    ) -> Result<()> {
+        if positions.len() != colors.len()
+            || tex_coords.map_or(false, |tex_coords| tex_coords.len() != positions.len())
+        {
+            return Err(invalid_geometry(
+                "the lengths of the vertex attributes are different".into(),
+            ));
+        }
+        if texture.is_some() && tex_coords.is_none() {
+            return Err(invalid_geometry(
+                "the texture coordinates are required with the texture".into(),
+            ));
+        }
+        check_triangles(positions.len(), indices)?;
+        let xy: Vec<f32> = positions.iter().flat_map(|p| [p.x, p.y]).collect();
+        let colors: Vec<bind::SDL_Color> = colors.iter().map(|&c| c.into()).collect();
+        let uv: Option<Vec<f32>> =
+            tex_coords.map(|tex_coords| tex_coords.iter().flat_map(|p| [p.x, p.y]).collect());
+        let (indices_ptr, indices_size) = indices.map_or((std::ptr::null(), 0), |i| i.as_raw());
+        let point_stride = (2 * size_of::<f32>()) as c_int;
+        let ret = unsafe {
+            bind::SDL_RenderGeometryRaw(
+                self.as_ptr(),
+                texture.map_or(std::ptr::null_mut(), Texture::as_ptr),
+                xy.as_ptr(),
+                point_stride,
+                colors.as_ptr(),
+                size_of::<bind::SDL_Color>() as c_int,
+                uv.as_ref().map_or(std::ptr::null(), |uv| uv.as_ptr()),
+                point_stride,
+                positions.len() as c_int,
+                indices_ptr,
+                indices.map_or(0, |indices| indices.len() as c_int),
+                indices_size,
+            )
+        };
+        if ret != 0 {
+            return Err(SdlError::Others { msg: Sdl::error() });
+        }
+        Ok(())
+    }
+}
@@ -3,7 +3,7 @@ use bitflags::bitflags;
 use crate::texture::Texture;
 use crate::{
     as_raw,
-    geo::{Point, Rect},
+    geo::{FPoint, FRect, Point, Rect},
 };
 use crate::{bind, EnumInt, Sdl};
 
@@ -40,12 +40,29 @@ pub struct PasteExOption {
     pub flip: PasteExFlip,
 }
 
+/// An option for [`PasteExt::paste_ex_f`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct PasteExFOption {
+    /// The target area of pasting in floating point, or whole if `None`.
+    pub target_area: Option<FRect>,
+    /// The degrees of rotating another texture.
+    pub rotation_degrees: f64,
+    /// The center point of pasting in floating point.
+    pub center: Option<FPoint>,
+    /// The flip mode of pasting.
+    pub flip: PasteExFlip,
+}
+
 /// An extension for [`Renderer`] to paste from another texture.
 pub trait PasteExt {
     /// Pastes the texture into `target_area`, or whole if `None`.
     fn paste(&self, texture: Texture, target_area: Option<Rect>);
     /// Pastes the texture with options [`PasteExOption`].
     fn paste_ex(&self, texture: Texture, options: PasteExOption);
+    /// Pastes the texture into `target_area` in floating point, or whole if `None`.
+    fn paste_f(&self, texture: Texture, target_area: Option<FRect>);
+    /// Pastes the texture with options [`PasteExFOption`] in floating point.
+    fn paste_ex_f(&self, texture: Texture, options: PasteExFOption);
 }
 
 impl PasteExt for Renderer<'_> {
@@ -88,4 +105,44 @@ impl PasteExt for Renderer<'_> {
             Sdl::error_then_panic("Pasting texture to renderer ex");
         }
     }
+
+    fn paste_f(&self, texture: Texture, target_area: Option<FRect>) {
+        let src = texture.clip().map(Into::into);
+        let dst = target_area.map(Into::into);
+        let ret = unsafe {
+            bind::SDL_RenderCopyF(self.as_ptr(), texture.as_ptr(), as_raw(&src), as_raw(&dst))
+        };
+        if ret != 0 {
+            Sdl::error_then_panic("Pasting texture to renderer");
+        }
+    }
+
+    fn paste_ex_f(
+        &self,
+        texture: Texture,
+        PasteExFOption {
+            target_area,
+            rotation_degrees,
+            center,
+            flip,
+        }: PasteExFOption,
+    ) {
+        let src = texture.clip().map(Into::into);
+        let dst = target_area.map(Into::into);
+        let center = center.map(Into::into);
+        let ret = unsafe {
+            bind::SDL_RenderCopyExF(
+                self.as_ptr(),
+                texture.as_ptr(),
+                as_raw(&src),
+                as_raw(&dst),
+                rotation_degrees,
+                as_raw(&center),
+                flip.bits as EnumInt,
+            )
+        };
+        if ret != 0 {
+            Sdl::error_then_panic("Pasting texture to renderer ex");
+        }
+    }
 }
@@ -1,8 +1,8 @@
 //! Rendering geometries and setting colors.
 
 use crate::color::{BlendMode, Rgb};
-use crate::geo::Rect;
-use crate::video::geo::{Line, Point};
+use crate::geo::{FRect, Rect};
+use crate::video::geo::{FPoint, Line, Point};
 use crate::{bind, Sdl};
 
 use super::Renderer;
@@ -11,18 +11,32 @@ use super::Renderer;
 #[derive(Debug)]
 pub struct Pen<'renderer> {
     renderer: &'renderer Renderer<'renderer>,
+    presents_on_drop: bool,
 }
 
 impl<'renderer> Pen<'renderer> {
-    /// Constructs a pen from the renderer [`Renderer`].
+    /// Constructs a pen from the renderer [`Renderer`]. It clears the renderer on constructed, and presents on dropped, unless a [`super::Frame`] of the renderer is alive. Use [`super::Frame::pen`] to draw with several pens in a frame.
     #[must_use]
     pub fn new(renderer: &'renderer Renderer) -> Self {
-        let this = Self { renderer };
+        if renderer.is_in_frame() {
+            return Self::without_present(renderer);
+        }
+        let this = Self {
+            renderer,
+            presents_on_drop: true,
+        };
         this.set_color(Rgb { r: 0, g: 0, b: 0 });
         this.clear();
         this
     }
 
+    pub(super) fn without_present(renderer: &'renderer Renderer) -> Self {
+        Self {
+            renderer,
+            presents_on_drop: false,
+        }
+    }
+
     /// Returns the renderer that the pen is drawing.
     #[must_use]
     pub fn renderer(&self) -> &Renderer {
@@ -166,10 +180,94 @@ impl<'renderer> Pen<'renderer> {
             Sdl::error_then_panic("Sdl pen rects")
         }
     }
+
+    /// Draws the line in floating point.
+    pub fn line_f(&self, start: FPoint, end: FPoint) {
+        let ret = unsafe {
+            bind::SDL_RenderDrawLineF(self.renderer.as_ptr(), start.x, start.y, end.x, end.y)
+        };
+        if ret != 0 {
+            Sdl::error_then_panic("Sdl pen line")
+        }
+    }
+
+    /// Draws the lines in floating point.
+    pub fn lines_f(&self, points: impl IntoIterator<Item = FPoint>) {
+        let points: Vec<_> = points.into_iter().map(Into::into).collect();
+        let ret = unsafe {
+            bind::SDL_RenderDrawLinesF(self.renderer.as_ptr(), points.as_ptr(), points.len() as i32)
+        };
+        if ret != 0 {
+            Sdl::error_then_panic("Sdl pen lines")
+        }
+    }
+
+    /// Draw the point in floating point.
+    pub fn point_f(&self, point: FPoint) {
+        let ret = unsafe { bind::SDL_RenderDrawPointF(self.renderer.as_ptr(), point.x, point.y) };
+        if ret != 0 {
+            Sdl::error_then_panic("Sdl pen point")
+        }
+    }
+
+    /// Draw the points in floating point.
+    pub fn points_f(&self, points: impl IntoIterator<Item = FPoint>) {
+        let points: Vec<_> = points.into_iter().map(Into::into).collect();
+        let ret = unsafe {
+            bind::SDL_RenderDrawPointsF(
+                self.renderer.as_ptr(),
+                points.as_ptr(),
+                points.len() as i32,
+            )
+        };
+        if ret != 0 {
+            Sdl::error_then_panic("Sdl pen points")
+        }
+    }
+
+    /// Draw the rectangle only lines in floating point.
+    pub fn stroke_rect_f(&self, rect: FRect) {
+        let ret = unsafe { bind::SDL_RenderDrawRectF(self.renderer.as_ptr(), &rect.into()) };
+        if ret != 0 {
+            Sdl::error_then_panic("Sdl pen rect")
+        }
+    }
+
+    /// Draw the rectangles only lines in floating point.
+    pub fn stroke_rects_f(&self, rects: impl IntoIterator<Item = FRect>) {
+        let rects: Vec<_> = rects.into_iter().map(Into::into).collect();
+        let ret = unsafe {
+            bind::SDL_RenderDrawRectsF(self.renderer.as_ptr(), rects.as_ptr(), rects.len() as i32)
+        };
+        if ret != 0 {
+            Sdl::error_then_panic("Sdl pen rects")
+        }
+    }
+
+    /// Draw the filled rectangle in floating point.
+    pub fn fill_rect_f(&self, rect: FRect) {
+        let ret = unsafe { bind::SDL_RenderFillRectF(self.renderer.as_ptr(), &rect.into()) };
+        if ret != 0 {
+            Sdl::error_then_panic("Sdl pen rect")
+        }
+    }
+
+    /// Draw the filled rectangles in floating point.
+    pub fn fill_rects_f(&self, rects: impl IntoIterator<Item = FRect>) {
+        let rects: Vec<_> = rects.into_iter().map(Into::into).collect();
+        let ret = unsafe {
+            bind::SDL_RenderFillRectsF(self.renderer.as_ptr(), rects.as_ptr(), rects.len() as i32)
+        };
+        if ret != 0 {
+            Sdl::error_then_panic("Sdl pen rects")
+        }
+    }
 }
 
 impl<'renderer> Drop for Pen<'renderer> {
     fn drop(&mut self) {
-        unsafe { bind::SDL_RenderPresent(self.renderer.as_ptr()) }
+        if self.presents_on_drop {
+            self.renderer.present();
+        }
     }
 }
@@ -5,7 +5,10 @@ use std::{os::raw::c_int, ptr::NonNull};
 use super::Surface;
 use crate::{
     bind,
-    color::pixel::{kind::BppMask, PixelFormat},
+    color::pixel::{
+        kind::{BppMask, PixelFormatKind},
+        PixelFormat,
+    },
     geo::Size,
     Result, Sdl, SdlError,
 };
@@ -47,6 +50,34 @@ impl Owned {
             |raw| Ok(Self { raw }),
         )
     }
+
+    /// Creates a new owned surface with its size and pixel format kind.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if `kind` is a FourCC format such as YUV, which a surface cannot have, or failed to allocate the surface.
+    pub fn with_format(size: Size, kind: PixelFormatKind) -> Result<Self> {
+        if let PixelFormatKind::FourCode(_) = kind {
+            return Err(SdlError::Others {
+                msg: "the surface cannot have a FourCC pixel format".into(),
+            });
+        }
+        let format = kind.as_raw();
+        let bits_per_pixel = (format >> 8) & 0xff;
+        let ptr = unsafe {
+            bind::SDL_CreateRGBSurfaceWithFormat(
+                0,
+                size.width as c_int,
+                size.height as c_int,
+                bits_per_pixel as c_int,
+                format,
+            )
+        };
+        NonNull::new(ptr).map_or_else(
+            || Err(SdlError::Others { msg: Sdl::error() }),
+            |raw| Ok(Self { raw }),
+        )
+    }
 }
 
 impl Drop for Owned {
@@ -2,17 +2,20 @@
 
 use static_assertions::assert_not_impl_all;
 use std::marker::PhantomData;
+use std::os::raw::c_int;
 use std::ptr::NonNull;
 
 use crate::color::Rgb;
 use crate::geo::{Rect, Size};
 use crate::renderer::Renderer;
 use crate::surface::Surface;
-use crate::{bind, EnumInt, Result, Sdl, SdlError};
+use crate::{as_raw, bind, EnumInt, Result, Sdl, SdlError};
 
+mod builder;
 pub mod lock;
 mod query;
 
+pub use builder::*;
 use lock::Lock;
 pub use query::*;
 
@@ -64,7 +67,7 @@ impl std::fmt::Debug for Texture<'_> {
 assert_not_impl_all!(Texture: Send, Sync);
 
 impl<'renderer> Texture<'renderer> {
-    /// Constructs a texture from the renderer with access type.
+    /// Constructs a texture from the renderer with access type, in the size and the pixel format of the window. Use [`TextureBuilder`] to specify them.
     ///
     /// # Errors
     ///
@@ -168,6 +171,171 @@ impl<'renderer> Texture<'renderer> {
         Lock::new(self, area)
     }
 
+    /// Updates the pixels in `area` of the texture, or whole if `None`, with `pixels` whose row is `pitch` bytes.
+    ///
+    /// This is slow, so use [`Texture::lock`] for the streaming texture instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if `area` is out of the texture, `pitch` is shorter than a row of `area`, `pixels` is too short for `area`, or failed to update the texture.
+    pub fn update(&self, area: Option<Rect>, pixels: &[u8], pitch: usize) -> Result<()> {
+        let (width, height) = self.update_area_size(area)?;
+        match self.pixel_layout()? {
+            PixelLayout::Packed(bytes_per_pixel) => {
+                let row_bytes = width
+                    .checked_mul(bytes_per_pixel)
+                    .ok_or_else(|| too_large_error(width))?;
+                ensure_plane(pixels, pitch, row_bytes, height)?;
+            }
+            layout => {
+                // The chroma planes follow the Y plane in `pixels`.
+                ensure_pitch(pitch, width)?;
+                let (chroma_width, chroma_height) = ((width + 1) / 2, (height + 1) / 2);
+                let (full_chroma_planes, chroma_pitch, chroma_row_bytes) =
+                    if let PixelLayout::Planar = layout {
+                        (1, (pitch + 1) / 2, chroma_width)
+                    } else {
+                        (0, 2 * ((pitch + 1) / 2), 2 * chroma_width)
+                    };
+                let required = pitch.checked_mul(height).and_then(|len| {
+                    let chroma_len = chroma_pitch.checked_mul(chroma_height)?;
+                    len.checked_add(chroma_len.checked_mul(full_chroma_planes)?)?
+                        .checked_add(plane_len(chroma_height, chroma_pitch, chroma_row_bytes)?)
+                });
+                ensure_len(pixels, required)?;
+            }
+        }
+        let area = area.map(Into::into);
+        let ret = unsafe {
+            bind::SDL_UpdateTexture(
+                self.as_ptr(),
+                as_raw(&area),
+                pixels.as_ptr().cast(),
+                pitch as c_int,
+            )
+        };
+        if ret != 0 {
+            return Err(SdlError::Others { msg: Sdl::error() });
+        }
+        Ok(())
+    }
+
+    /// Updates the pixels in `area` of the planar YV12 or IYUV texture, or whole if `None`, with each plane and its pitch. The U and V planes are half the height of `area`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if `area` is out of the texture, a pitch is shorter than a row of its plane, a plane is too short for `area`, or failed to update the texture.
+    pub fn update_yuv(
+        &self,
+        area: Option<Rect>,
+        (y_plane, y_pitch): (&[u8], usize),
+        (u_plane, u_pitch): (&[u8], usize),
+        (v_plane, v_pitch): (&[u8], usize),
+    ) -> Result<()> {
+        let (width, height) = self.update_area_size(area)?;
+        let (chroma_width, chroma_height) = ((width + 1) / 2, (height + 1) / 2);
+        ensure_plane(y_plane, y_pitch, width, height)?;
+        ensure_plane(u_plane, u_pitch, chroma_width, chroma_height)?;
+        ensure_plane(v_plane, v_pitch, chroma_width, chroma_height)?;
+        let area = area.map(Into::into);
+        let ret = unsafe {
+            bind::SDL_UpdateYUVTexture(
+                self.as_ptr(),
+                as_raw(&area),
+                y_plane.as_ptr(),
+                y_pitch as c_int,
+                u_plane.as_ptr(),
+                u_pitch as c_int,
+                v_plane.as_ptr(),
+                v_pitch as c_int,
+            )
+        };
+        if ret != 0 {
+            return Err(SdlError::Others { msg: Sdl::error() });
+        }
+        Ok(())
+    }
+
+    /// Updates the pixels in `area` of the planar NV12 or NV21 texture, or whole if `None`, with each plane and its pitch. The interleaved UV plane is half the height of `area`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if `area` is out of the texture, a pitch is shorter than a row of its plane, a plane is too short for `area`, or failed to update the texture.
+    pub fn update_nv(
+        &self,
+        area: Option<Rect>,
+        (y_plane, y_pitch): (&[u8], usize),
+        (uv_plane, uv_pitch): (&[u8], usize),
+    ) -> Result<()> {
+        let (width, height) = self.update_area_size(area)?;
+        let (chroma_width, chroma_height) = ((width + 1) / 2, (height + 1) / 2);
+        ensure_plane(y_plane, y_pitch, width, height)?;
+        ensure_plane(uv_plane, uv_pitch, 2 * chroma_width, chroma_height)?;
+        let area = area.map(Into::into);
+        let ret = unsafe {
+            bind::SDL_UpdateNVTexture(
+                self.as_ptr(),
+                as_raw(&area),
+                y_plane.as_ptr(),
+                y_pitch as c_int,
+                uv_plane.as_ptr(),
+                uv_pitch as c_int,
+            )
+        };
+        if ret != 0 {
+            return Err(SdlError::Others { msg: Sdl::error() });
+        }
+        Ok(())
+    }
+
+    fn update_area_size(&self, area: Option<Rect>) -> Result<(usize, usize)> {
+        let Size { width, height } = self.size();
+        let area = match area {
+            Some(area) => area,
+            None => return Ok((width as usize, height as usize)),
+        };
+        let fits = |start: i32, len: u32, max: u32| {
+            u32::try_from(start)
+                .ok()
+                .and_then(|start| start.checked_add(len))
+                .map_or(false, |end| end <= max)
+        };
+        if !fits(area.up_left.x, area.size.width, width)
+            || !fits(area.up_left.y, area.size.height, height)
+        {
+            return Err(SdlError::Others {
+                msg: format!(
+                    "the area {:?} is out of the texture of {}x{}",
+                    area, width, height
+                ),
+            });
+        }
+        Ok((area.size.width as usize, area.size.height as usize))
+    }
+
+    fn pixel_layout(&self) -> Result<PixelLayout> {
+        use std::ptr::null_mut;
+        let mut raw_format = 0u32;
+        let ret = unsafe {
+            bind::SDL_QueryTexture(
+                self.as_ptr(),
+                &mut raw_format,
+                null_mut(),
+                null_mut(),
+                null_mut(),
+            )
+        };
+        if ret != 0 {
+            return Err(SdlError::Others { msg: Sdl::error() });
+        }
+        PixelLayout::from_raw(raw_format).ok_or_else(|| SdlError::Others {
+            msg: format!(
+                "the texture format {:#x} is unsupported to update",
+                raw_format
+            ),
+        })
+    }
+
     /// Return the clip area of the texture if available.
     #[must_use]
     pub fn clip(&self) -> &Option<Rect> {
@@ -215,3 +383,81 @@ impl Drop for Texture<'_> {
         unsafe { bind::SDL_DestroyTexture(self.as_ptr()) }
     }
 }
+
+/// A memory layout of the pixels for a texture format.
+#[derive(Debug, Clone, Copy)]
+enum PixelLayout {
+    /// Each pixel in the row is stored in the bytes.
+    Packed(usize),
+    /// The Y plane is followed by the U and V planes subsampled in half, such as YV12 and IYUV.
+    Planar,
+    /// The Y plane is followed by the interleaved UV plane subsampled in half, such as NV12 and NV21.
+    SemiPlanar,
+}
+
+impl PixelLayout {
+    fn from_raw(raw: u32) -> Option<Self> {
+        let is_four_cc = raw != 0 && (raw >> 28) & 0x0f != 1;
+        if !is_four_cc {
+            let bytes_per_pixel = (raw & 0xff) as usize;
+            return (bytes_per_pixel != 0).then(|| PixelLayout::Packed(bytes_per_pixel));
+        }
+        match raw as EnumInt {
+            bind::SDL_PIXELFORMAT_YUY2
+            | bind::SDL_PIXELFORMAT_UYVY
+            | bind::SDL_PIXELFORMAT_YVYU => Some(PixelLayout::Packed(2)),
+            bind::SDL_PIXELFORMAT_YV12 | bind::SDL_PIXELFORMAT_IYUV => Some(PixelLayout::Planar),
+            bind::SDL_PIXELFORMAT_NV12 | bind::SDL_PIXELFORMAT_NV21 => {
+                Some(PixelLayout::SemiPlanar)
+            }
+            _ => None,
+        }
+    }
+}
+
+fn too_large_error(width: usize) -> SdlError {
+    SdlError::Others {
+        msg: format!("the row of {} pixels is too large", width),
+    }
+}
+
+/// Returns the bytes needed for `rows` rows of `row_bytes` bytes every `pitch` bytes, or `None` on overflow.
+fn plane_len(rows: usize, pitch: usize, row_bytes: usize) -> Option<usize> {
+    if rows == 0 {
+        return Some(0);
+    }
+    (rows - 1).checked_mul(pitch)?.checked_add(row_bytes)
+}
+
+fn ensure_pitch(pitch: usize, row_bytes: usize) -> Result<()> {
+    if pitch < row_bytes || c_int::try_from(pitch).is_err() {
+        return Err(SdlError::Others {
+            msg: format!(
+                "the pitch {} is out of range for the row of {} bytes",
+                pitch, row_bytes
+            ),
+        });
+    }
+    Ok(())
+}
+
+fn ensure_len(plane: &[u8], required: Option<usize>) -> Result<()> {
+    match required {
+        Some(required) if required <= plane.len() => Ok(()),
+        Some(required) => Err(SdlError::Others {
+            msg: format!(
+                "the plane of {} bytes is shorter than {} bytes",
+                plane.len(),
+                required
+            ),
+        }),
+        None => Err(SdlError::Others {
+            msg: "the plane size overflowed".into(),
+        }),
+    }
+}
+
+fn ensure_plane(plane: &[u8], pitch: usize, row_bytes: usize, rows: usize) -> Result<()> {
+    ensure_pitch(pitch, row_bytes)?;
+    ensure_len(plane, plane_len(rows, pitch, row_bytes))
+}
@@ -0,0 +1,51 @@
+use std::marker::PhantomData;
+use std::os::raw::c_int;
+use std::ptr::NonNull;
+use typed_builder::TypedBuilder;
+
+use super::{Texture, TextureAccess};
+use crate::color::pixel::kind::PixelFormatKind;
+use crate::geo::Size;
+use crate::renderer::Renderer;
+use crate::{bind, Result, Sdl, SdlError};
+
+/// A builder for the [`Texture`] with the explicit size and format.
+#[derive(Debug, TypedBuilder)]
+pub struct TextureBuilder {
+    size: Size,
+    format: PixelFormatKind,
+    #[builder(default = TextureAccess::Static)]
+    access: TextureAccess,
+}
+
+impl TextureBuilder {
+    /// Builds the texture for the renderer.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if failed to allocate, the format was unsupported, or the width or height were out of range.
+    pub fn new_texture<'renderer>(
+        self,
+        renderer: &'renderer Renderer<'renderer>,
+    ) -> Result<Texture<'renderer>> {
+        NonNull::new(unsafe {
+            bind::SDL_CreateTexture(
+                renderer.as_ptr(),
+                self.format.as_raw(),
+                self.access.as_raw() as c_int,
+                self.size.width as c_int,
+                self.size.height as c_int,
+            )
+        })
+        .map_or_else(
+            || Err(SdlError::Others { msg: Sdl::error() }),
+            |texture| {
+                Ok(Texture {
+                    texture,
+                    clip: None,
+                    _phantom: PhantomData,
+                })
+            },
+        )
+    }
+}
@@ -70,6 +70,17 @@ impl<'video> Window<'video> {
         NonNull::new(raw).map(|window| Self { window, video })
     }
 
+    /// Gets the id of a window having the keyboard focus, or `None` if does not exist. Compare it with [`Window::id`] to find the window.
+    #[must_use]
+    pub fn keyboard_focused_id(_: &'video Video) -> Option<u32> {
+        let raw = unsafe { bind::SDL_GetKeyboardFocus() };
+        if raw.is_null() {
+            return None;
+        }
+        let id = unsafe { bind::SDL_GetWindowID(raw) };
+        (id != 0).then(|| id)
+    }
+
     pub(crate) fn as_ptr(&self) -> *mut bind::SDL_Window {
         self.window.as_ptr()
     }